@@ -0,0 +1,70 @@
+use std::sync::Arc;
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use serde::Deserialize;
+
+use rpc_core::{
+    call::{CurrentCall, IncomingCall},
+    json::JsonValue,
+    transport::TransportContext,
+};
+
+#[allow(dead_code)]
+#[derive(Deserialize)]
+struct Item {
+    id: u64,
+    name: String,
+    tags: Vec<String>,
+    score: f64,
+}
+
+#[allow(dead_code)]
+#[derive(Deserialize)]
+struct Call<A> {
+    key: String,
+    proc: usize,
+    args: Option<A>,
+}
+
+fn payload(items: usize) -> String {
+    let items = (0..items)
+        .map(|i| format!(r#"{{"id":{i},"name":"item {i}","tags":["a","b","c"],"score":{i}.5}}"#))
+        .collect::<Vec<_>>()
+        .join(",");
+
+    format!(r#"{{"key":"1","proc":0,"args":[{items}]}}"#)
+}
+
+/// Compare decoding of call args through `JsonValue` tree and through the extractor path,
+/// that keeps args of `IncomingCall` as raw JSON and decodes them in `CurrentCall`
+fn decode_args(c: &mut Criterion) {
+    let mut group = c.benchmark_group("decode_args");
+
+    for items in [1, 100, 10_000] {
+        let payload = payload(items);
+
+        group.bench_with_input(BenchmarkId::new("json_value", items), &payload, |b, payload| {
+            b.iter(|| {
+                let call: Call<JsonValue> = serde_json::from_str(black_box(payload)).unwrap();
+                let args: Vec<Item> = serde_json::from_value(call.args.clone().unwrap()).unwrap();
+                black_box(args)
+            })
+        });
+
+        group.bench_with_input(BenchmarkId::new("extractor", items), &payload, |b, payload| {
+            let transport = Arc::new(TransportContext::local());
+
+            b.iter(|| {
+                let incoming: IncomingCall = serde_json::from_str(black_box(payload)).unwrap();
+                let call = CurrentCall::new(incoming, transport.clone());
+                let args: Vec<Item> = call.decode_args().ok().flatten().expect("args are decoded");
+                black_box(args)
+            })
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, decode_args);
+criterion_main!(benches);
@@ -1,17 +1,27 @@
-use std::sync::Arc;
+use std::{
+    any::Any,
+    sync::{Arc, OnceLock},
+    time::Instant,
+};
 
 use dbg::only_dbg;
-use errs::Catch;
+use errs::{Catch, Error};
 use injector::InjectorRef;
 
 use crate::{
-    call::{CurrentCall, IncomingCalls},
+    call::{CallKey, CallMeta, CurrentCall, IncomingCalls},
+    deprecation::DeprecatedCalls,
+    error_policy::ErrorPolicy,
     errors,
     extractors::AppInfo,
     json::JsonValue,
-    procedure::response::ProcedureResponse,
+    messages::MessageCatalog,
+    procedure::response::{ProcedureResponse, ResponseExt},
     router::{BuildedRouter, Routers},
+    runtime,
     schema::build_schema,
+    state::States,
+    transport::TransportContextRef,
 };
 
 #[derive(Debug)]
@@ -19,26 +29,126 @@ pub struct App {
     pub info: AppInfo,
     pub routers: Routers,
     pub builded_router: BuildedRouter,
+    pub states: States,
+    /// Translated messages of error codes
+    pub messages: MessageCatalog,
+    /// Policy of error details returned to clients
+    pub error_policy: ErrorPolicy,
+    /// Tracking of calls made to deprecated procedures
+    pub deprecated_calls: DeprecatedCalls,
+    /// Fingerprint of the schema, computed on first use
+    fingerprint: OnceLock<String>,
 }
 
 pub type AppRef = Arc<App>;
 
 impl App {
-    pub fn new(info: AppInfo, mut routers: Routers) -> Self {
-        let builded_router = BuildedRouter::new(&mut routers);
+    pub fn new(info: AppInfo, routers: Routers) -> Self {
+        Self::with_states(info, States::new(), routers)
+    }
+
+    /// Make app with one typed state, that can be read by `State<S>` extractor
+    ///
+    /// # Panics
+    ///
+    /// Panics if some procedure requires state that is not provided.
+    pub fn with_state<S: Any + Send + Sync>(info: AppInfo, state: S, routers: Routers) -> Self {
+        Self::with_states(info, States::new().with(state), routers)
+    }
+
+    /// Make app with typed states, that can be read by `State<S>` extractor
+    ///
+    /// # Panics
+    ///
+    /// Panics if some procedure requires state that is not provided.
+    pub fn with_states(info: AppInfo, states: States, mut routers: Routers) -> Self {
+        let builded_router = BuildedRouter::new(&mut routers, &states);
 
         Self {
             info,
             routers,
             builded_router,
+            states,
+            messages: MessageCatalog::builtin(),
+            error_policy: ErrorPolicy::default(),
+            deprecated_calls: DeprecatedCalls::default(),
+            fingerprint: OnceLock::new(),
         }
     }
 
+    /// Replace catalog of translated error messages
+    pub fn with_messages(mut self, messages: MessageCatalog) -> Self {
+        self.messages = messages;
+        self
+    }
+
+    /// Replace policy of error details returned to clients
+    pub fn with_error_policy(mut self, error_policy: ErrorPolicy) -> Self {
+        self.error_policy = error_policy;
+        self
+    }
+
+    /// Replace tracking of calls made to deprecated procedures
+    pub fn with_deprecated_calls(mut self, deprecated_calls: DeprecatedCalls) -> Self {
+        self.deprecated_calls = deprecated_calls;
+        self
+    }
+
+    /// Mount built-in `$rpc` router with app info and introspection procedures,
+    /// eg. `$rpc/get_schema` or `$rpc/describe_procedure`
+    ///
+    /// The router is added after routers of the app, so ids of their procedures do not change.
+    pub fn with_runtime(mut self) -> Self {
+        // Router is mounted only once
+        if self.routers.iter().any(|router| &*router.name == runtime::RUNTIME_ROUTER_NAME) {
+            return self;
+        }
+
+        self.routers.push(runtime::router());
+        self.builded_router = BuildedRouter::new(&mut self.routers, &self.states);
+        self.fingerprint = OnceLock::new();
+        self
+    }
+
+    /// Fingerprint of the schema, clients send it in call metadata to detect other schema
+    pub fn fingerprint(&self) -> &str {
+        self.fingerprint.get_or_init(|| build_schema(self).fingerprint)
+    }
+
+    /// Check that the client was generated from the schema of the app
+    fn check_fingerprint(&self, meta: &CallMeta) -> Catch<()> {
+        match &meta.fingerprint {
+            Some(expected) if expected != self.fingerprint() => {
+                Err(errors::schema_version_mismatch(expected, self.fingerprint()))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Apply error policy and translated messages to the response
+    pub(crate) fn finish_response(&self, response: &mut ProcedureResponse, meta: &CallMeta) {
+        response.redact(&self.error_policy);
+        response.localize(&self.messages, meta.locale.as_deref());
+    }
+
+    /// Response of the call that failed outside of the procedure, eg. procedure was not found
+    fn failed_response(&self, key: CallKey, error: Error, meta: &CallMeta, start: Instant) -> JsonValue {
+        let mut response = ProcedureResponse::error(key, error);
+        self.finish_response(&mut response, meta);
+
+        let ext = ResponseExt::new(start.elapsed(), Vec::new(), None);
+
+        response.with_ext(ext).into()
+    }
+
     /// Process request
+    ///
+    /// Transport context is supplied by transport that received the request.
     pub async fn process_request(
         &self,
         app_ref: AppRef,
         injector_ref: InjectorRef,
+        transport: TransportContextRef,
         calls: IncomingCalls,
     ) -> Catch<Vec<JsonValue>> {
         let calls_len = calls.len();
@@ -51,18 +161,28 @@ impl App {
 
         let mut futures = Vec::with_capacity(calls_len);
         for call in calls {
+            let start = Instant::now();
+
+            // Ids of procedures of other schema may point to other procedures
+            if let Err(error) = self.check_fingerprint(&call.meta) {
+                results.push(self.failed_response(call.key, error, &call.meta, start));
+                continue;
+            }
+
             let proc_id = call.proc;
             let procedure = self.builded_router.find_procedure(proc_id);
 
             let procedure = match procedure {
                 Some(p) => p,
                 None => {
-                    results.push(ProcedureResponse::error(call.key, errors::procedure_not_found()).into());
+                    results.push(self.failed_response(call.key, errors::procedure_not_found(), &call.meta, start));
                     continue;
                 }
             };
 
-            let current_call = CurrentCall::new(call);
+            let key = call.key.clone();
+            let meta = call.meta.clone();
+            let current_call = CurrentCall::new(call, transport.clone());
 
             let app = app_ref.clone();
             let injector = injector_ref.clone();
@@ -88,13 +208,23 @@ impl App {
                 response
             });
 
-            futures.push(future);
+            futures.push((key, meta, start, future));
         }
 
-        for future in futures {
+        for (key, meta, start, future) in futures {
             let result = future.await;
             let result = match result {
                 Ok(r) => r,
+                Err(e) if e.is_panic() => {
+                    // Panic of procedure is returned as error of the call
+                    let payload = e.into_panic();
+                    let detail = match payload.downcast_ref::<&str>() {
+                        Some(detail) => detail.to_string(),
+                        None => payload.downcast_ref::<String>().cloned().unwrap_or_default(),
+                    };
+
+                    self.failed_response(key, errors::procedure_panicked(detail), &meta, start)
+                }
                 Err(_) => return Err(errors::one_of_calls_failed()),
             };
 
@@ -113,4 +243,20 @@ impl App {
 
         schema.into()
     }
+
+    /// Generate OpenAPI 3.1 document
+    ///
+    /// Every procedure is described as POST operation under its path.
+    pub fn openapi(&self) -> JsonValue {
+        let schema = build_schema(self);
+
+        schema.to_openapi()
+    }
+
+    /// Generate JSON Schema (draft 2020-12) bundle of all types
+    pub fn json_schema(&self) -> JsonValue {
+        let schema = build_schema(self);
+
+        schema.to_json_schema()
+    }
 }
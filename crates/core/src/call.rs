@@ -1,17 +1,35 @@
 use std::sync::Arc;
 
-use serde::Deserialize;
+use serde::{de::DeserializeOwned, Deserialize};
 
-use crate::{json::JsonValue, procedure::ProcedureId};
+use errs::Catch;
+
+use crate::{
+    errors, extractors::Warnings, json::JsonRawValue, procedure::ProcedureId, transport::TransportContextRef,
+};
 
 pub type CallKey = Arc<str>;
-pub type CallArgs = Option<JsonValue>;
+/// Raw JSON of call arguments, deserialized directly by extractors
+pub type CallArgs = Option<Box<JsonRawValue>>;
+
+/// Metadata sent by client with the call
+#[derive(Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct CallMeta {
+    /// Preferred locale of the messages, eg. `cs-CZ`
+    pub locale: Option<String>,
+    /// Fingerprint of the schema the client was generated from,
+    /// the call is rejected if it differs from the schema of the server
+    pub fingerprint: Option<String>,
+}
 
 #[derive(Deserialize, Debug, Clone)]
 pub struct IncomingCall {
     pub key: CallKey,
     pub proc: ProcedureId,
     pub args: CallArgs,
+    #[serde(default)]
+    pub meta: CallMeta,
 }
 
 pub type IncomingCalls = Vec<IncomingCall>;
@@ -19,13 +37,33 @@ pub type IncomingCalls = Vec<IncomingCall>;
 pub struct CurrentCall {
     pub key: CallKey,
     pub args: CallArgs,
+    pub meta: CallMeta,
+    pub transport: TransportContextRef,
+    pub warnings: Warnings,
 }
 
 impl CurrentCall {
-    pub fn new(incoming: IncomingCall) -> Self {
+    pub fn new(incoming: IncomingCall, transport: TransportContextRef) -> Self {
         Self {
             key: incoming.key,
             args: incoming.args,
+            meta: incoming.meta,
+            transport,
+            warnings: Warnings::new(),
+        }
+    }
+    /// Decode raw JSON of call args, `None` if the call has no args
+    ///
+    /// Used by `Args` and `OptionalArgs` extractors.
+    pub fn decode_args<T: DeserializeOwned>(&self) -> Catch<Option<T>> {
+        let args = match self.args.as_deref() {
+            Some(args) => args,
+            _ => return Ok(None),
+        };
+
+        match serde_json::from_str(args.get()) {
+            Ok(value) => Ok(Some(value)),
+            Err(e) => Err(errors::unparsable_call_args(e.to_string())),
         }
     }
 }
@@ -0,0 +1,53 @@
+use std::{collections::HashMap, sync::Mutex};
+
+use crate::procedure::{ProcedureId, ProcedureName};
+
+/// Tracking of calls made to deprecated procedures
+///
+/// Nothing is tracked by default, calls can be logged and counted.
+#[derive(Debug, Default)]
+pub struct DeprecatedCalls {
+    /// Log every call of deprecated procedure
+    pub log: bool,
+    /// Count calls of deprecated procedures
+    pub count: bool,
+    counts: Mutex<HashMap<ProcedureId, u64>>,
+}
+
+impl DeprecatedCalls {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Log every call of deprecated procedure as warning
+    pub fn logged(mut self) -> Self {
+        self.log = true;
+        self
+    }
+
+    /// Count calls of deprecated procedures
+    pub fn counted(mut self) -> Self {
+        self.count = true;
+        self
+    }
+
+    /// Number of calls of deprecated procedure
+    pub fn calls(&self, id: ProcedureId) -> u64 {
+        self.counts.lock().unwrap().get(&id).copied().unwrap_or_default()
+    }
+
+    /// Number of calls of all called deprecated procedures
+    pub fn counts(&self) -> HashMap<ProcedureId, u64> {
+        self.counts.lock().unwrap().clone()
+    }
+
+    pub(crate) fn record(&self, id: ProcedureId, name: &ProcedureName, note: &str) {
+        if self.log {
+            tracing::warn!("Call of deprecated procedure({}) {}: {}", id, name, note);
+        }
+
+        if self.count {
+            *self.counts.lock().unwrap().entry(id).or_default() += 1;
+        }
+    }
+}
@@ -0,0 +1,68 @@
+use std::collections::HashSet;
+
+use serde_json::Map;
+
+use crate::json::JsonValue;
+
+/// Policy of error details returned to clients
+///
+/// Debug builds show all details, production builds show only error codes,
+/// except the codes that are explicitly allowed.
+#[derive(Debug, Clone)]
+pub struct ErrorPolicy {
+    /// Show details of all errors
+    pub show_details: bool,
+    /// Codes of errors whose details are always shown
+    pub allowed_codes: HashSet<String>,
+}
+
+impl ErrorPolicy {
+    /// Policy that shows details of all errors
+    pub fn debug() -> Self {
+        Self {
+            show_details: true,
+            allowed_codes: HashSet::new(),
+        }
+    }
+
+    /// Policy that shows only error codes
+    pub fn production() -> Self {
+        Self {
+            show_details: false,
+            allowed_codes: HashSet::new(),
+        }
+    }
+
+    /// Allow details of the error code
+    pub fn allow(mut self, code: &str) -> Self {
+        self.allowed_codes.insert(code.to_string());
+        self
+    }
+
+    /// Check if details of the error code may be shown
+    pub fn shows_details(&self, code: &str) -> bool {
+        self.show_details || self.allowed_codes.contains(code)
+    }
+
+    /// Remove details of serialized error if they may not be shown
+    pub fn redact(&self, err: &mut Map<String, JsonValue>) {
+        let shows_details = match err.get("code") {
+            Some(JsonValue::String(code)) => self.shows_details(code),
+            _ => self.show_details,
+        };
+
+        if !shows_details {
+            err.remove("detail");
+        }
+    }
+}
+
+impl Default for ErrorPolicy {
+    fn default() -> Self {
+        if cfg!(debug_assertions) {
+            Self::debug()
+        } else {
+            Self::production()
+        }
+    }
+}
@@ -1,6 +1,13 @@
 use std::fmt::Display;
 
 use errs::{code::HttpCode, Error};
+use serde::Serialize;
+
+/// Typed procedure error, implemented by `RpcError` derive
+pub trait RpcError: Serialize {
+    fn code(&self) -> &'static str;
+    fn http_code(&self) -> u16;
+}
 
 pub fn one_of_calls_failed() -> Error {
     Error::new(codes::RPC_CORE_ONE_OF_CALLS_FAILED, HttpCode::InternalServerError, None)
@@ -26,10 +33,51 @@ pub fn injector_not_found() -> Error {
     Error::new(codes::RPC_CORE_INJECTOR_NOT_FOUND, HttpCode::InternalServerError, None)
 }
 
+pub fn unserializable_response<D: Display>(detail: D) -> Error {
+    Error::new(
+        codes::RPC_CORE_UNSERIALIZABLE_RESPONSE,
+        HttpCode::InternalServerError,
+        Some(detail.to_string()),
+    )
+}
+
+pub fn unloadable_message_catalog<D: Display>(detail: D) -> Error {
+    Error::new(
+        codes::RPC_CORE_UNLOADABLE_MESSAGE_CATALOG,
+        HttpCode::InternalServerError,
+        Some(detail.to_string()),
+    )
+}
+
+pub fn procedure_panicked<D: Display>(detail: D) -> Error {
+    Error::new(
+        codes::RPC_CORE_PROCEDURE_PANICKED,
+        HttpCode::InternalServerError,
+        Some(detail.to_string()),
+    )
+}
+
+pub fn state_not_found() -> Error {
+    Error::new(codes::RPC_CORE_STATE_NOT_FOUND, HttpCode::InternalServerError, None)
+}
+
+pub fn schema_version_mismatch(expected: &str, actual: &str) -> Error {
+    Error::new(
+        codes::RPC_CORE_SCHEMA_VERSION_MISMATCH,
+        HttpCode::BadRequest,
+        Some(format!("client expects schema `{expected}`, server runs schema `{actual}`")),
+    )
+}
+
 pub mod codes {
     pub const RPC_CORE_ONE_OF_CALLS_FAILED: &str = "RPC_CORE_ONE_OF_CALLS_FAILED";
     pub const RPC_CORE_PROCEDURE_NOT_FOUND: &str = "RPC_CORE_PROCEDURE_NOT_FOUND";
     pub const RPC_CORE_EMPTY_CALL_ARGS: &str = "RPC_CORE_EMPTY_CALL_ARGS";
     pub const RPC_CORE_UNPARSABLE_CALL_ARGS: &str = "RPC_CORE_UNPARSABLE_CALL_ARGS";
     pub const RPC_CORE_INJECTOR_NOT_FOUND: &str = "RPC_CORE_INJECTOR_NOT_FOUND";
+    pub const RPC_CORE_STATE_NOT_FOUND: &str = "RPC_CORE_STATE_NOT_FOUND";
+    pub const RPC_CORE_UNSERIALIZABLE_RESPONSE: &str = "RPC_CORE_UNSERIALIZABLE_RESPONSE";
+    pub const RPC_CORE_UNLOADABLE_MESSAGE_CATALOG: &str = "RPC_CORE_UNLOADABLE_MESSAGE_CATALOG";
+    pub const RPC_CORE_PROCEDURE_PANICKED: &str = "RPC_CORE_PROCEDURE_PANICKED";
+    pub const RPC_CORE_SCHEMA_VERSION_MISMATCH: &str = "RPC_CORE_SCHEMA_VERSION_MISMATCH";
 }
@@ -3,7 +3,7 @@ use serde::{Deserialize, Serialize};
 use errs::Catch;
 use injector::InjectorRef;
 
-use rpc_openschema::applike::AppInfoLike;
+use rpc_openschema::{applike::AppInfoLike, schema::TypeMapRef, SchemaProcedure, SchemableParams};
 
 use crate::{app::AppRef, call::CurrentCall, from_request::FromRequest};
 
@@ -47,3 +47,8 @@ impl FromRequest for AppInfo {
         Ok(app.info.clone())
     }
 }
+
+impl SchemableParams for AppInfo {
+    #[inline]
+    fn apply_schema(_proc: &mut SchemaProcedure, _: TypeMapRef) {}
+}
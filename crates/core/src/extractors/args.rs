@@ -21,18 +21,10 @@ where
     T: DeserializeOwned,
 {
     fn from_request(_app: &AppRef, _injector: &InjectorRef, call: &CurrentCall) -> Catch<Self> {
-        let args = match call.args.clone() {
-            Some(args) => args,
-            _ => return Err(errors::empty_call_args()),
-        };
-
-        let value = serde_json::from_value(args);
-        let value = match value {
-            Ok(value) => Self(value),
-            Err(e) => return Err(errors::unparsable_call_args(e.to_string())),
-        };
-
-        Ok(value)
+        match call.decode_args()? {
+            Some(value) => Ok(Self(value)),
+            None => Err(errors::empty_call_args()),
+        }
     }
 }
 
@@ -69,18 +61,7 @@ where
     T: DeserializeOwned,
 {
     fn from_request(_app: &AppRef, _injector: &InjectorRef, call: &CurrentCall) -> Catch<Self> {
-        let args = match call.args.clone() {
-            Some(args) => args,
-            _ => return Ok(Self(None)),
-        };
-
-        let value = serde_json::from_value(args);
-        let value = match value {
-            Ok(value) => Self(value),
-            Err(e) => return Err(errors::unparsable_call_args(e.to_string())),
-        };
-
-        Ok(value)
+        Ok(Self(call.decode_args()?))
     }
 }
 
@@ -1,7 +1,15 @@
 mod app;
 mod args;
+mod peer;
 mod provide;
+mod schema;
+mod state;
+mod warnings;
 
 pub use app::AppInfo;
 pub use args::{Args, OptionalArgs};
+pub use peer::Peer;
 pub use provide::Provide;
+pub use schema::AppSchema;
+pub use state::State;
+pub use warnings::Warnings;
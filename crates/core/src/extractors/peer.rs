@@ -0,0 +1,44 @@
+use std::ops::Deref;
+
+use errs::Catch;
+use injector::InjectorRef;
+
+use rpc_openschema::{schema::TypeMapRef, SchemaProcedure, SchemableParams};
+
+use crate::{
+    app::AppRef,
+    call::CurrentCall,
+    from_request::FromRequest,
+    transport::{TransportContext, TransportContextRef},
+};
+
+/// Connection info of the peer supplied by transport
+pub struct Peer(pub TransportContextRef);
+
+impl Peer {
+    #[inline]
+    pub fn inner(self) -> TransportContextRef {
+        self.0
+    }
+}
+
+impl Deref for Peer {
+    type Target = TransportContext;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl FromRequest for Peer {
+    #[inline]
+    fn from_request(_app: &AppRef, _injector: &InjectorRef, call: &CurrentCall) -> Catch<Self> {
+        Ok(Self(call.transport.clone()))
+    }
+}
+
+impl SchemableParams for Peer {
+    #[inline]
+    fn apply_schema(_proc: &mut SchemaProcedure, _: TypeMapRef) {}
+}
@@ -0,0 +1,42 @@
+use std::ops::Deref;
+
+use errs::Catch;
+use injector::InjectorRef;
+
+use rpc_openschema::{
+    schema::{SchemaRoot, TypeMapRef},
+    SchemaProcedure, SchemableParams,
+};
+
+use crate::{app::AppRef, call::CurrentCall, extractors::AppInfo, from_request::FromRequest, schema::build_schema};
+
+/// Schema of the app, built when the call arrives
+pub struct AppSchema(pub SchemaRoot<AppInfo>);
+
+impl AppSchema {
+    #[inline]
+    pub fn inner(self) -> SchemaRoot<AppInfo> {
+        self.0
+    }
+}
+
+impl Deref for AppSchema {
+    type Target = SchemaRoot<AppInfo>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl FromRequest for AppSchema {
+    #[inline]
+    fn from_request(app: &AppRef, _injector: &InjectorRef, _call: &CurrentCall) -> Catch<Self> {
+        Ok(Self(build_schema(app)))
+    }
+}
+
+impl SchemableParams for AppSchema {
+    #[inline]
+    fn apply_schema(_proc: &mut SchemaProcedure, _: TypeMapRef) {}
+}
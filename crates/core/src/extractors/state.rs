@@ -0,0 +1,48 @@
+use std::{any::Any, ops::Deref, sync::Arc};
+
+use errs::Catch;
+use injector::InjectorRef;
+
+use rpc_openschema::{schema::TypeMapRef, SchemaProcedure, SchemableParams};
+
+use crate::{app::AppRef, call::CurrentCall, errors, from_request::FromRequest, state::StateKey};
+
+/// Typed state provided to App by `App::with_state`
+pub struct State<S: Any + Send + Sync>(pub Arc<S>);
+
+impl<S: Any + Send + Sync> State<S> {
+    #[inline]
+    pub fn inner(self) -> Arc<S> {
+        self.0
+    }
+}
+
+impl<S: Any + Send + Sync> Deref for State<S> {
+    type Target = S;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<S: Any + Send + Sync> FromRequest for State<S> {
+    fn from_request(app: &AppRef, _injector: &InjectorRef, _call: &CurrentCall) -> Catch<Self> {
+        let value = app.states.get::<S>();
+
+        match value {
+            None => Err(errors::state_not_found()),
+            Some(value) => Ok(Self(value)),
+        }
+    }
+
+    #[inline]
+    fn required_states(states: &mut Vec<StateKey>) {
+        states.push(StateKey::of::<S>());
+    }
+}
+
+impl<S: Any + Send + Sync> SchemableParams for State<S> {
+    #[inline]
+    fn apply_schema(_proc: &mut SchemaProcedure, _: TypeMapRef) {}
+}
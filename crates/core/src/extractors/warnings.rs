@@ -0,0 +1,43 @@
+use std::{
+    fmt::Display,
+    sync::{Arc, Mutex},
+};
+
+use errs::Catch;
+use injector::InjectorRef;
+
+use rpc_openschema::{schema::TypeMapRef, SchemaProcedure, SchemableParams};
+
+use crate::{app::AppRef, call::CurrentCall, from_request::FromRequest};
+
+/// Non-fatal warnings of the call, returned in response extensions
+#[derive(Clone, Default, Debug)]
+pub struct Warnings(Arc<Mutex<Vec<String>>>);
+
+impl Warnings {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Push warning to the response
+    pub fn push<D: Display>(&self, warning: D) {
+        self.0.lock().unwrap().push(warning.to_string());
+    }
+
+    /// Take all pushed warnings
+    pub(crate) fn take(&self) -> Vec<String> {
+        std::mem::take(&mut *self.0.lock().unwrap())
+    }
+}
+
+impl FromRequest for Warnings {
+    #[inline]
+    fn from_request(_app: &AppRef, _injector: &InjectorRef, call: &CurrentCall) -> Catch<Self> {
+        Ok(call.warnings.clone())
+    }
+}
+
+impl SchemableParams for Warnings {
+    #[inline]
+    fn apply_schema(_proc: &mut SchemaProcedure, _: TypeMapRef) {}
+}
@@ -1,12 +1,17 @@
 use errs::Catch;
 use injector::InjectorRef;
 
-use crate::{app::AppRef, call::CurrentCall};
+use crate::{app::AppRef, call::CurrentCall, state::StateKey};
 
 /// A trait that takes a request and tries to convert it into arguments for a
 /// procedure.
 pub trait FromRequest: Sized {
     fn from_request(app: &AppRef, injector: &InjectorRef, call: &CurrentCall) -> Catch<Self>;
+
+    /// Collect states that must be provided to App, so missing state is
+    /// detected when the App is built and not when the call arrives.
+    #[inline]
+    fn required_states(_states: &mut Vec<StateKey>) {}
 }
 
 macro_rules! factory_tuple ({ $($param:ident)* } => {
@@ -22,6 +27,11 @@ macro_rules! factory_tuple ({ $($param:ident)* } => {
         ) -> Catch<Self> {
             Ok(($($param::from_request(_app, _injector, _call)?,)*))
         }
+
+        #[inline]
+        fn required_states(_states: &mut Vec<StateKey>) {
+            $($param::required_states(_states);)*
+        }
     }
 });
 
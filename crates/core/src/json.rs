@@ -1 +1,34 @@
+use rpc_openschema::{schema::TypeMapRef, SchemaProcedure, SchemableResult};
+use serde::Serialize;
+
 pub type JsonValue = serde_json::Value;
+pub type JsonRawValue = serde_json::value::RawValue;
+
+/// Wrapper that responds with any serializable value
+#[derive(Serialize, Debug, Clone)]
+#[serde(transparent)]
+pub struct Json<T: Serialize>(pub T);
+
+impl<T: Serialize> Json<T> {
+    #[inline]
+    pub fn inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T: Serialize> From<T> for Json<T> {
+    #[inline]
+    fn from(value: T) -> Self {
+        Self(value)
+    }
+}
+
+impl<T> SchemableResult for Json<T>
+where
+    T: Serialize + SchemableResult,
+{
+    #[inline]
+    fn apply_schema(proc: &mut SchemaProcedure, type_map: TypeMapRef) {
+        T::apply_schema(proc, type_map)
+    }
+}
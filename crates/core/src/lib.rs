@@ -1,12 +1,17 @@
 pub mod app;
 pub mod call;
+pub mod deprecation;
+pub mod error_policy;
 pub mod errors;
 pub mod extractors;
 pub mod from_request;
 pub mod helpers;
 pub mod json;
+pub mod messages;
 pub mod procedure;
 pub mod responder;
 pub mod router;
 pub mod runtime;
 pub mod schema;
+pub mod state;
+pub mod transport;
@@ -0,0 +1,137 @@
+use std::{collections::HashMap, fs, path::Path};
+
+use errs::Catch;
+
+use crate::errors;
+
+pub type Messages = HashMap<String, String>;
+
+/// Default locale of the built-in catalog
+pub const DEFAULT_LOCALE: &str = "en";
+
+/// Built-in catalogs of core error codes
+const BUILTIN_CATALOGS: [(&str, &str); 2] = [
+    ("en", include_str!("../messages/en.json")),
+    ("cs", include_str!("../messages/cs.json")),
+];
+
+/// Catalog of translated error messages by locale and error code
+///
+/// Messages are templates, `{code}` and `{detail}` are replaced by error code and error detail.
+/// Template using `{detail}` is stored under `CODE.detail` and it is used only if the error has detail,
+/// so errors whose detail was removed by error policy get the plain template of `CODE`.
+#[derive(Debug, Clone)]
+pub struct MessageCatalog {
+    default_locale: String,
+    locales: HashMap<String, Messages>,
+}
+
+impl MessageCatalog {
+    /// Empty catalog
+    pub fn new(default_locale: &str) -> Self {
+        Self {
+            default_locale: default_locale.to_string(),
+            locales: HashMap::new(),
+        }
+    }
+
+    /// Catalog with messages for all core error codes
+    pub fn builtin() -> Self {
+        let mut catalog = Self::new(DEFAULT_LOCALE);
+
+        for (locale, source) in BUILTIN_CATALOGS {
+            // Built-in catalogs are valid JSON
+            catalog.add_json(locale, source).unwrap();
+        }
+
+        catalog
+    }
+
+    /// Add messages of locale, existing messages are replaced
+    pub fn add(&mut self, locale: &str, messages: Messages) {
+        self.locales.entry(locale.to_string()).or_default().extend(messages);
+    }
+
+    /// Add messages of locale from JSON object of error codes and templates
+    pub fn add_json(&mut self, locale: &str, source: &str) -> Catch<()> {
+        let messages: Messages = match serde_json::from_str(source) {
+            Ok(messages) => messages,
+            Err(e) => return Err(errors::unloadable_message_catalog(e)),
+        };
+
+        self.add(locale, messages);
+
+        Ok(())
+    }
+
+    /// Add messages from JSON file, locale is the file stem (eg. `cs.json`)
+    pub fn load_file<P: AsRef<Path>>(&mut self, path: P) -> Catch<()> {
+        let path = path.as_ref();
+
+        let locale = match path.file_stem().and_then(|stem| stem.to_str()) {
+            Some(locale) => locale.to_string(),
+            None => return Err(errors::unloadable_message_catalog(path.display())),
+        };
+
+        let source = match fs::read_to_string(path) {
+            Ok(source) => source,
+            Err(e) => return Err(errors::unloadable_message_catalog(e)),
+        };
+
+        self.add_json(&locale, &source)
+    }
+
+    /// Add messages from all JSON files in directory
+    pub fn load_dir<P: AsRef<Path>>(&mut self, path: P) -> Catch<()> {
+        let entries = match fs::read_dir(path) {
+            Ok(entries) => entries,
+            Err(e) => return Err(errors::unloadable_message_catalog(e)),
+        };
+
+        for entry in entries {
+            let path = match entry {
+                Ok(entry) => entry.path(),
+                Err(e) => return Err(errors::unloadable_message_catalog(e)),
+            };
+
+            if path.extension().is_some_and(|ext| ext == "json") {
+                self.load_file(path)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Find message of the error code
+    ///
+    /// Locale is matched exactly, then by its language (`cs-CZ` -> `cs`),
+    /// then default locale is used.
+    pub fn message(&self, locale: Option<&str>, code: &str, detail: Option<&str>) -> Option<String> {
+        let detail_code = format!("{code}.detail");
+
+        let template = locale
+            .into_iter()
+            .flat_map(|locale| [locale, locale.split(['-', '_']).next().unwrap_or(locale)])
+            .chain([self.default_locale.as_str()])
+            .find_map(|locale| {
+                let messages = self.locales.get(locale)?;
+
+                // Template with detail is used only if there is detail to fill in
+                detail
+                    .and_then(|_| messages.get(&detail_code))
+                    .or_else(|| messages.get(code))
+            })?;
+
+        let message = template
+            .replace("{code}", code)
+            .replace("{detail}", detail.unwrap_or_default());
+
+        Some(message)
+    }
+}
+
+impl Default for MessageCatalog {
+    fn default() -> Self {
+        Self::builtin()
+    }
+}
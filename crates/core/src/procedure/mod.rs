@@ -1,18 +1,19 @@
-use std::{fmt::Debug, sync::Arc};
+use std::{fmt::Debug, sync::Arc, time::Instant};
 
 use injector::InjectorRef;
 
 use rpc_openschema::{
     procedurelike::ProcedureLike,
-    schema::{ProcedureType, TypeMapRef},
-    SchemaProcedure, SchemableParams, SchemableResult,
+    schema::{field_value, ProcedureType, SchemaExample, TypeMapRef},
+    SchemaProcedure, SchemableError, SchemableParams, SchemableResult,
 };
 use serde::Serialize;
 
-use crate::{app::AppRef, call::CurrentCall, from_request::FromRequest, json::JsonValue};
+use crate::{app::AppRef, call::CurrentCall, from_request::FromRequest, json::JsonValue, state::StateKey};
 
 use self::{
     procedureable::Procedureable,
+    response::ResponseExt,
     schema::{new_procedure_schema_service, ProcedureSchemaService},
     service::{new_procedure_service, ProcedureService},
 };
@@ -24,6 +25,7 @@ pub mod service;
 
 pub type ProcedureId = usize;
 pub type ProcedureName = Arc<str>;
+pub type ProcedureStates = Arc<[StateKey]>;
 
 pub(crate) type ProcedureServiceRef = Arc<ProcedureService>;
 pub(crate) type ProcedureSchemaServiceRef = Arc<ProcedureSchemaService>;
@@ -35,6 +37,10 @@ pub struct Procedure {
     pub(crate) ty: ProcedureType,
     pub(crate) service: ProcedureServiceRef,
     pub(crate) schema: ProcedureSchemaServiceRef,
+    pub(crate) states: ProcedureStates,
+    pub(crate) deprecation: Option<Arc<str>>,
+    pub(crate) description: Option<Arc<str>>,
+    pub(crate) examples: Arc<[SchemaExample]>,
 }
 
 impl Procedure {
@@ -43,6 +49,7 @@ impl Procedure {
         ty: ProcedureType,
         service: ProcedureServiceRef,
         schema: ProcedureSchemaServiceRef,
+        states: ProcedureStates,
     ) -> Self {
         Self {
             id: None,
@@ -50,6 +57,10 @@ impl Procedure {
             ty,
             service,
             schema,
+            states,
+            deprecation: None,
+            description: None,
+            examples: Arc::from([]),
         }
     }
 
@@ -57,16 +68,18 @@ impl Procedure {
         name: &str,
         service: ProcedureServiceRef,
         schema: ProcedureSchemaServiceRef,
+        states: ProcedureStates,
     ) -> Self {
-        Self::init(name, ProcedureType::Query, service, schema)
+        Self::init(name, ProcedureType::Query, service, schema, states)
     }
 
     pub fn new_mutation(
         name: &str,
         service: ProcedureServiceRef,
         schema: ProcedureSchemaServiceRef,
+        states: ProcedureStates,
     ) -> Self {
-        Self::init(name, ProcedureType::Mutation, service, schema)
+        Self::init(name, ProcedureType::Mutation, service, schema, states)
     }
 
     pub fn id(&self) -> ProcedureId {
@@ -81,6 +94,70 @@ impl Procedure {
         self.ty.clone()
     }
 
+    /// States required by procedure arguments
+    pub fn states(&self) -> ProcedureStates {
+        self.states.clone()
+    }
+
+    /// Mark procedure as deprecated, the note is returned with every response
+    pub fn deprecated(&mut self, note: &str) -> &mut Self {
+        self.deprecation = Some(Arc::from(note));
+        self
+    }
+
+    pub fn deprecation(&self) -> Option<Arc<str>> {
+        self.deprecation.clone()
+    }
+
+    /// Describe procedure, the description is part of the schema
+    pub fn describe(&mut self, description: &str) -> &mut Self {
+        self.description = Some(Arc::from(description));
+        self
+    }
+
+    pub fn description(&self) -> Option<Arc<str>> {
+        self.description.clone()
+    }
+
+    /// Add example call of procedure, the example is part of the schema
+    ///
+    /// Example that cannot be serialized is left out with a warning.
+    pub fn example<P: Serialize, R: Serialize>(&mut self, params: P, result: R) -> &mut Self {
+        let example = match (field_value(&params), field_value(&result)) {
+            (Some(params), Some(result)) => SchemaExample { params, result },
+            _ => {
+                tracing::warn!("Example of procedure `{}` cannot be serialized, it is skipped", self.name);
+                return self;
+            }
+        };
+
+        let mut examples = self.examples.to_vec();
+        examples.push(example);
+        self.examples = Arc::from(examples);
+
+        self
+    }
+
+    /// Describe typed errors of the procedure, eg. enum deriving `RpcError`,
+    /// error catalogue of the type replaces opaque error of the procedure result in the schema
+    pub fn errors<E: SchemableError + 'static>(&mut self) -> &mut Self {
+        let schema = self.schema.clone();
+        let errors: ProcedureSchemaService =
+            Box::new(move |(procedure_schema, type_map): (SchemaProcedure, TypeMapRef)| {
+                let mut procedure_schema = schema((procedure_schema, type_map.clone()));
+                E::apply_schema(&mut procedure_schema, type_map);
+
+                procedure_schema
+            });
+
+        self.schema = Arc::new(errors);
+        self
+    }
+
+    pub fn examples(&self) -> Arc<[SchemaExample]> {
+        self.examples.clone()
+    }
+
     pub(crate) fn set_id(&mut self, id: ProcedureId) {
         self.id = Some(id);
     }
@@ -91,8 +168,23 @@ impl Procedure {
         injector: InjectorRef,
         call: CurrentCall,
     ) -> JsonValue {
-        let future = (self.service)((app, injector, call));
-        future.await
+        let warnings = call.warnings.clone();
+        let meta = call.meta.clone();
+
+        if let Some(note) = &self.deprecation {
+            app.deprecated_calls.record(self.id(), &self.name, note);
+        }
+
+        let start = Instant::now();
+
+        let future = (self.service)((app.clone(), injector, call));
+        let mut response = future.await;
+
+        app.finish_response(&mut response, &meta);
+
+        let ext = ResponseExt::new(start.elapsed(), warnings.take(), self.deprecation.clone());
+
+        response.with_ext(ext).into()
     }
 }
 
@@ -109,6 +201,18 @@ impl ProcedureLike for Procedure {
         &self.name
     }
 
+    fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    fn deprecation(&self) -> Option<&str> {
+        self.deprecation.as_deref()
+    }
+
+    fn examples(&self) -> &[SchemaExample] {
+        &self.examples
+    }
+
     fn call_schema(
         &self,
         procedure_schema: SchemaProcedure,
@@ -124,6 +228,10 @@ impl Debug for Procedure {
             .field("id", &self.id)
             .field("name", &self.name)
             .field("ty", &self.ty)
+            .field("states", &self.states)
+            .field("deprecation", &self.deprecation)
+            .field("description", &self.description)
+            .field("examples", &self.examples)
             .finish()
     }
 }
@@ -151,8 +259,9 @@ impl Procedures {
     }
 
     /// Add procedure to router
-    fn add(&mut self, procedure: Procedure) {
+    fn add(&mut self, procedure: Procedure) -> &mut Procedure {
         self.items.push(procedure);
+        self.items.last_mut().unwrap()
     }
 
     /// Insert clone of procedure to procedures
@@ -160,11 +269,11 @@ impl Procedures {
         procedure.set_id(id);
 
         let cloned_procedure = procedure.clone();
-        self.add(cloned_procedure);
+        self.items.push(cloned_procedure);
     }
 
     /// Add query procedure to router
-    pub fn add_query<F, Args>(&mut self, name: &str, procedure: F)
+    pub fn add_query<F, Args>(&mut self, name: &str, procedure: F) -> &mut Procedure
     where
         F: Procedureable<Args>,
         Args: FromRequest + SchemableParams + Send,
@@ -173,12 +282,14 @@ impl Procedures {
         let service = new_procedure_service(procedure.clone());
         let schema = new_procedure_schema_service(procedure);
 
-        let procedure = Procedure::new_query(name, Arc::new(service), Arc::new(schema));
-        self.add(procedure);
+        let states = required_states::<Args>();
+
+        let procedure = Procedure::new_query(name, Arc::new(service), Arc::new(schema), states);
+        self.add(procedure)
     }
 
     /// Add mutation procedure to router
-    pub fn add_mutation<F, Args>(&mut self, name: &str, procedure: F)
+    pub fn add_mutation<F, Args>(&mut self, name: &str, procedure: F) -> &mut Procedure
     where
         F: Procedureable<Args>,
         Args: FromRequest + SchemableParams + Send,
@@ -187,7 +298,16 @@ impl Procedures {
         let service = new_procedure_service(procedure.clone());
         let schema = new_procedure_schema_service(procedure);
 
-        let procedure = Procedure::new_mutation(name, Arc::new(service), Arc::new(schema));
-        self.add(procedure);
+        let states = required_states::<Args>();
+
+        let procedure = Procedure::new_mutation(name, Arc::new(service), Arc::new(schema), states);
+        self.add(procedure)
     }
 }
+
+fn required_states<Args: FromRequest>() -> ProcedureStates {
+    let mut states = Vec::new();
+    Args::required_states(&mut states);
+
+    Arc::from(states)
+}
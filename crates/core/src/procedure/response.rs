@@ -1,6 +1,35 @@
+use std::{sync::Arc, time::Duration};
+
 use serde::Serialize;
+use serde_json::Map;
+
+use crate::{call::CallKey, error_policy::ErrorPolicy, errors, json::JsonValue, messages::MessageCatalog};
+
+/// Extensions of the response envelope
+///
+/// Attached to every response of a call, also when the procedure was not found or panicked.
+#[derive(Serialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ResponseExt {
+    /// Server execution time in microseconds
+    pub duration_us: u64,
+    /// Non-fatal warnings pushed by procedure
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<String>,
+    /// Deprecation notice of the procedure
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deprecation: Option<Arc<str>>,
+}
 
-use crate::{call::CallKey, json::JsonValue};
+impl ResponseExt {
+    pub fn new(duration: Duration, warnings: Vec<String>, deprecation: Option<Arc<str>>) -> Self {
+        Self {
+            duration_us: duration.as_micros() as u64,
+            warnings,
+            deprecation,
+        }
+    }
+}
 
 #[derive(Serialize)]
 pub struct ProcedureResponse {
@@ -9,11 +38,19 @@ pub struct ProcedureResponse {
     pub ok: Option<JsonValue>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub err: Option<JsonValue>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ext: Option<ResponseExt>,
 }
 
 impl ProcedureResponse {
     fn init(key: CallKey, ok: Option<JsonValue>, err: Option<JsonValue>) -> Self {
-        Self { key, ok, err }
+        Self { key, ok, err, ext: None }
+    }
+
+    /// Set extensions of the response
+    pub fn with_ext(mut self, ext: ResponseExt) -> Self {
+        self.ext = Some(ext);
+        self
     }
 
     /// Empty response
@@ -22,26 +59,97 @@ impl ProcedureResponse {
     }
 
     /// Response with result
+    ///
+    /// If result can not be serialized, response with serialization error is returned.
     pub fn result<T>(key: CallKey, result: T) -> Self
     where
         T: Serialize,
     {
-        let result = serde_json::to_value(result).unwrap();
-        Self::init(key, Some(result), None)
+        match serde_json::to_value(result) {
+            Ok(result) => Self::init(key, Some(result), None),
+            Err(e) => Self::unserializable(key, e),
+        }
     }
 
     /// Response with error
+    ///
+    /// If error can not be serialized, response with serialization error is returned.
     pub fn error<T>(key: CallKey, error: T) -> Self
     where
         T: Serialize,
     {
-        let error = serde_json::to_value(error).unwrap();
+        match serde_json::to_value(error) {
+            Ok(error) => Self::init(key, None, Some(error)),
+            Err(e) => Self::unserializable(key, e),
+        }
+    }
+
+    /// Remove error details that may not be shown by policy
+    pub fn redact(&mut self, policy: &ErrorPolicy) {
+        if let Some(JsonValue::Object(err)) = &mut self.err {
+            policy.redact(err);
+        }
+    }
+
+    /// Add translated message of error code to the error
+    pub fn localize(&mut self, messages: &MessageCatalog, locale: Option<&str>) {
+        let err = match &mut self.err {
+            Some(JsonValue::Object(err)) => err,
+            _ => return,
+        };
+
+        let code = match err.get("code") {
+            Some(JsonValue::String(code)) => code,
+            _ => return,
+        };
+
+        let detail = err.get("detail").and_then(JsonValue::as_str);
+
+        if let Some(message) = messages.message(locale, code, detail) {
+            err.insert(String::from("message"), JsonValue::String(message));
+        }
+    }
+
+    /// Response with serialization error
+    fn unserializable(key: CallKey, detail: serde_json::Error) -> Self {
+        let error = errors::unserializable_response(detail);
+        let error = match serde_json::to_value(error) {
+            Ok(error) => error,
+            Err(_) => JsonValue::String(errors::codes::RPC_CORE_UNSERIALIZABLE_RESPONSE.to_string()),
+        };
+
         Self::init(key, None, Some(error))
     }
 }
 
 impl From<ProcedureResponse> for JsonValue {
     fn from(call: ProcedureResponse) -> Self {
-        serde_json::to_value(call).unwrap()
+        let mut map = Map::with_capacity(3);
+        map.insert(String::from("key"), JsonValue::String(call.key.to_string()));
+
+        if let Some(ok) = call.ok {
+            map.insert(String::from("ok"), ok);
+        }
+
+        if let Some(err) = call.err {
+            map.insert(String::from("err"), err);
+        }
+
+        if let Some(ext) = call.ext {
+            match serde_json::to_value(ext) {
+                Ok(ext) => {
+                    map.insert(String::from("ext"), ext);
+                }
+                Err(e) => {
+                    tracing::warn!(
+                        "Extensions of response `{}` cannot be serialized, they are skipped: {}",
+                        call.key,
+                        e
+                    );
+                }
+            }
+        }
+
+        JsonValue::Object(map)
     }
 }
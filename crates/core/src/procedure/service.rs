@@ -1,14 +1,11 @@
 use callbacks::Callback;
 use injector::InjectorRef;
 
-use crate::{
-    app::AppRef, call::CurrentCall, from_request::FromRequest, json::JsonValue,
-    responder::Responder,
-};
+use crate::{app::AppRef, call::CurrentCall, from_request::FromRequest, responder::Responder};
 
 use super::{response::ProcedureResponse, Procedureable};
 
-pub type ProcedureService = Box<Callback<(AppRef, InjectorRef, CurrentCall), JsonValue>>;
+pub type ProcedureService = Box<Callback<(AppRef, InjectorRef, CurrentCall), ProcedureResponse>>;
 
 pub fn new_procedure_service<F, Args>(procedure: F) -> ProcedureService
 where
@@ -22,18 +19,13 @@ where
         Box::pin(async move {
             let args = Args::from_request(&app, &injector, &call);
 
-            let response = match args {
+            match args {
                 Ok(args) => {
                     let response = procedure.call(args).await;
-                    response.into_json(call.key)
+                    response.into_response(call.key)
                 }
-                Err(err) => {
-                    let response = ProcedureResponse::error(call.key, err);
-                    response.into()
-                }
-            };
-
-            response.into()
+                Err(err) => ProcedureResponse::error(call.key, err),
+            }
         })
     })
 }
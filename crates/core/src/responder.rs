@@ -1,16 +1,21 @@
-use std::sync::Arc;
+use std::{
+    borrow::Cow,
+    collections::{BTreeMap, HashMap},
+    sync::Arc,
+};
 
+use rpc_openschema::Bytes;
 use serde::Serialize;
 
-use crate::{call::CallKey, json::JsonValue, procedure::response::ProcedureResponse};
+use crate::{call::CallKey, json::Json, procedure::response::ProcedureResponse};
 
 pub trait Responder {
-    fn into_json(self, call_key: CallKey) -> JsonValue;
+    fn into_response(self, call_key: CallKey) -> ProcedureResponse;
 }
 
 impl Responder for () {
-    fn into_json(self, call_key: CallKey) -> JsonValue {
-        ProcedureResponse::empty(call_key).into()
+    fn into_response(self, call_key: CallKey) -> ProcedureResponse {
+        ProcedureResponse::empty(call_key)
     }
 }
 
@@ -18,8 +23,8 @@ macro_rules! factory_responder {
     ($($type:ty), *) => {
         $(
             impl Responder for $type {
-                fn into_json(self, call_key: CallKey) -> JsonValue {
-                    ProcedureResponse::result(call_key, self).into()
+                fn into_response(self, call_key: CallKey) -> ProcedureResponse {
+                    ProcedureResponse::result(call_key, self)
                 }
             }
         )*
@@ -44,29 +49,86 @@ factory_responder!(
     f64,
     String,
     &str,
-    Arc<str>
+    Arc<str>,
+    Bytes
 );
 
 impl<T: Serialize> Responder for Option<T> {
-    fn into_json(self, call_key: CallKey) -> JsonValue {
+    fn into_response(self, call_key: CallKey) -> ProcedureResponse {
         match self {
-            Some(result) => ProcedureResponse::result(call_key, result).into(),
-            None => ProcedureResponse::empty(call_key).into(),
+            Some(result) => ProcedureResponse::result(call_key, result),
+            None => ProcedureResponse::empty(call_key),
         }
     }
 }
 
 impl<Ok: Serialize, Err: Serialize> Responder for Result<Ok, Err> {
-    fn into_json(self, call_key: CallKey) -> JsonValue {
+    fn into_response(self, call_key: CallKey) -> ProcedureResponse {
         match self {
-            Ok(result) => ProcedureResponse::result(call_key, result).into(),
-            Err(error) => ProcedureResponse::error(call_key, error).into(),
+            Ok(result) => ProcedureResponse::result(call_key, result),
+            Err(error) => ProcedureResponse::error(call_key, error),
         }
     }
 }
 
 impl<T: Serialize> Responder for Vec<T> {
-    fn into_json(self, call_key: CallKey) -> JsonValue {
-        ProcedureResponse::result(call_key, self).into()
+    fn into_response(self, call_key: CallKey) -> ProcedureResponse {
+        ProcedureResponse::result(call_key, self)
     }
 }
+
+impl<T: Serialize> Responder for Json<T> {
+    fn into_response(self, call_key: CallKey) -> ProcedureResponse {
+        ProcedureResponse::result(call_key, self.0)
+    }
+}
+
+impl<K: Serialize, V: Serialize> Responder for HashMap<K, V> {
+    fn into_response(self, call_key: CallKey) -> ProcedureResponse {
+        ProcedureResponse::result(call_key, self)
+    }
+}
+
+impl<K: Serialize, V: Serialize> Responder for BTreeMap<K, V> {
+    fn into_response(self, call_key: CallKey) -> ProcedureResponse {
+        ProcedureResponse::result(call_key, self)
+    }
+}
+
+impl<T: Serialize> Responder for Box<T> {
+    fn into_response(self, call_key: CallKey) -> ProcedureResponse {
+        ProcedureResponse::result(call_key, self)
+    }
+}
+
+impl<T: Serialize> Responder for Arc<T> {
+    fn into_response(self, call_key: CallKey) -> ProcedureResponse {
+        ProcedureResponse::result(call_key, self)
+    }
+}
+
+impl Responder for Cow<'_, str> {
+    fn into_response(self, call_key: CallKey) -> ProcedureResponse {
+        ProcedureResponse::result(call_key, self)
+    }
+}
+
+macro_rules! factory_responder_tuple ({ $($param:ident)* } => {
+    impl<$($param,)*> Responder for ($($param,)*)
+    where
+        $($param: Serialize,)*
+    {
+        fn into_response(self, call_key: CallKey) -> ProcedureResponse {
+            ProcedureResponse::result(call_key, self)
+        }
+    }
+});
+
+factory_responder_tuple! { A }
+factory_responder_tuple! { A B }
+factory_responder_tuple! { A B C }
+factory_responder_tuple! { A B C D }
+factory_responder_tuple! { A B C D E }
+factory_responder_tuple! { A B C D E F }
+factory_responder_tuple! { A B C D E F G }
+factory_responder_tuple! { A B C D E F G H }
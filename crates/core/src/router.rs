@@ -7,6 +7,7 @@ use crate::{
     from_request::FromRequest,
     helpers::function_name,
     procedure::{procedureable::Procedureable, Procedure, ProcedureId, Procedures},
+    state::States,
 };
 
 #[derive(Debug)]
@@ -42,7 +43,7 @@ impl Router {
     }
 
     /// Add query procedure to router
-    pub fn add_query<F, Args>(&mut self, procedure: F)
+    pub fn add_query<F, Args>(&mut self, procedure: F) -> &mut Procedure
     where
         F: Procedureable<Args>,
         Args: FromRequest + SchemableParams + Send,
@@ -50,11 +51,11 @@ impl Router {
     {
         let name = function_name::<F>();
 
-        self.procedures.add_query(name, procedure);
+        self.procedures.add_query(name, procedure)
     }
 
     /// Add mutation procedure to router
-    pub fn add_mutation<F, Args>(&mut self, procedure: F)
+    pub fn add_mutation<F, Args>(&mut self, procedure: F) -> &mut Procedure
     where
         F: Procedureable<Args>,
         Args: FromRequest + SchemableParams + Send,
@@ -62,7 +63,7 @@ impl Router {
     {
         let name = function_name::<F>();
 
-        self.procedures.add_mutation(name, procedure);
+        self.procedures.add_mutation(name, procedure)
     }
 }
 
@@ -78,31 +79,46 @@ impl BuildedRouter {
         }
     }
 
-    pub(crate) fn new(routers: &mut Routers) -> Self {
+    /// Build flatten router
+    ///
+    /// # Panics
+    ///
+    /// Panics if some procedure requires state that is not provided to App.
+    pub(crate) fn new(routers: &mut Routers, states: &States) -> Self {
         let mut id = 0;
         let mut builded_router = Self::empty();
 
         for router in routers {
-            builded_router.add_router(router, &mut id);
+            builded_router.add_router(router, states, &mut id);
         }
 
         builded_router
     }
 
-    fn add_router(&mut self, router: &mut Router, id: &mut ProcedureId) {
+    fn add_router(&mut self, router: &mut Router, states: &States, id: &mut ProcedureId) {
         // Add procedures
-        self.add_procedures(&mut router.procedures, id);
+        self.add_procedures(&mut router.procedures, states, id);
 
         // Add inner routers
         for inner_router in &mut router.routers {
-            self.add_router(inner_router, id);
+            self.add_router(inner_router, states, id);
         }
     }
 
-    fn add_procedures(&mut self, procedures: &mut Procedures, id: &mut ProcedureId) {
+    fn add_procedures(&mut self, procedures: &mut Procedures, states: &States, id: &mut ProcedureId) {
         /* procedures */
 
         for mut procedure in &mut procedures.items {
+            // Check that all required states are provided
+            for state in procedure.states.iter() {
+                if !states.contains(state) {
+                    panic!(
+                        "Procedure `{}` requires state `{}` that is not provided to App",
+                        procedure.name, state.name
+                    );
+                }
+            }
+
             // Add procedure to flatten router
             self.flatten_router.insert(&mut procedure, id.clone());
 
@@ -1 +1,21 @@
+use crate::router::Router;
+
 pub mod procedures;
+
+/// Name of the built-in router of runtime procedures
+pub const RUNTIME_ROUTER_NAME: &str = "$rpc";
+
+/// Make built-in router with runtime and introspection procedures
+pub fn router() -> Router {
+    let mut router = Router::new(RUNTIME_ROUTER_NAME);
+
+    router.add_query(procedures::get_app_name);
+    router.add_query(procedures::get_app_version);
+    router.add_query(procedures::get_app_description);
+    router.add_query(procedures::get_schema);
+    router.add_query(procedures::get_schema_fingerprint);
+    router.add_query(procedures::list_procedures);
+    router.add_query(procedures::describe_procedure);
+
+    router
+}
@@ -1,4 +1,10 @@
-use crate::extractors::AppInfo;
+use errs::Catch;
+
+use crate::{
+    errors,
+    extractors::{AppInfo, AppSchema, Args},
+    json::{Json, JsonValue},
+};
 
 /// Runtime procedure
 /// This procedure is used to get app name
@@ -17,3 +23,38 @@ pub async fn get_app_version(app: AppInfo) -> String {
 pub async fn get_app_description(app: AppInfo) -> String {
     app.description.clone()
 }
+
+/// Runtime procedure
+/// This procedure is used to get schema of the app
+pub async fn get_schema(schema: AppSchema) -> Json<JsonValue> {
+    Json(schema.inner().into())
+}
+
+/// Runtime procedure
+/// This procedure is used to get fingerprint of the schema,
+/// client sends it in call metadata to detect that it was generated from other schema
+pub async fn get_schema_fingerprint(schema: AppSchema) -> String {
+    schema.fingerprint.clone()
+}
+
+/// Runtime procedure
+/// This procedure is used to list paths of all procedures, eg. `users/get_user`
+pub async fn list_procedures(schema: AppSchema) -> Vec<String> {
+    schema.procedures.iter().map(|procedure| procedure.full_path()).collect()
+}
+
+/// Runtime procedure
+/// This procedure is used to get schema of one procedure by its path
+pub async fn describe_procedure(path: Args<String>, schema: AppSchema) -> Catch<Json<JsonValue>> {
+    let path = path.inner();
+
+    let procedure = schema.procedures.iter().find(|procedure| procedure.full_path() == path);
+    let procedure = match procedure {
+        Some(procedure) => procedure,
+        None => return Err(errors::procedure_not_found()),
+    };
+
+    serde_json::to_value(procedure)
+        .map(Json)
+        .map_err(errors::unserializable_response)
+}
@@ -1,7 +1,7 @@
 use std::{rc::Rc, sync::Arc};
 
 use rpc_openschema::{
-    schema::{new_type_map_ref, SchemaRoot, TypeMapRef},
+    schema::{new_error_map, new_type_map_ref, SchemaRoot, TypeMapRef},
     SchemaProcedure, SCHEMA_PATH_SEPARATOR, SCHEMA_VERSION,
 };
 
@@ -19,12 +19,23 @@ pub(crate) fn build_schema(app: &App) -> SchemaRoot<AppInfo> {
 
     let structs = Rc::try_unwrap(structs).unwrap().into_inner().unwrap();
 
-    let root = SchemaRoot {
+    // Collect error catalogue of all procedures
+    let mut errors = new_error_map();
+    for procedure in &procedures {
+        for error in &procedure.errors {
+            errors.insert(error.code.clone(), error.clone());
+        }
+    }
+
+    let mut root = SchemaRoot {
         rpcapi: SCHEMA_VERSION.to_string(),
+        fingerprint: String::new(),
         info: app.info.clone(),
         procedures,
         types: structs,
+        errors,
     };
+    root.fingerprint = root.compute_fingerprint();
 
     root
 }
@@ -0,0 +1,69 @@
+use std::{
+    any::{type_name, Any, TypeId},
+    collections::HashMap,
+    fmt::Debug,
+    sync::Arc,
+};
+
+type StateValue = Arc<dyn Any + Send + Sync>;
+
+/// Key of typed state required by procedure
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StateKey {
+    pub id: TypeId,
+    pub name: &'static str,
+}
+
+impl StateKey {
+    #[inline]
+    pub fn of<S: Any + Send + Sync>() -> Self {
+        Self {
+            id: TypeId::of::<S>(),
+            name: type_name::<S>(),
+        }
+    }
+}
+
+/// Typed application states stored on App
+#[derive(Default)]
+pub struct States {
+    items: HashMap<TypeId, (StateKey, StateValue)>,
+}
+
+impl States {
+    pub fn new() -> Self {
+        Self { items: HashMap::new() }
+    }
+
+    /// Add state, replacing the previous state of the same type
+    pub fn insert<S: Any + Send + Sync>(&mut self, state: S) {
+        let key = StateKey::of::<S>();
+
+        self.items.insert(key.id, (key, Arc::new(state)));
+    }
+
+    /// Add state and return self
+    pub fn with<S: Any + Send + Sync>(mut self, state: S) -> Self {
+        self.insert(state);
+        self
+    }
+
+    /// Get state by type
+    pub fn get<S: Any + Send + Sync>(&self) -> Option<Arc<S>> {
+        let (_, value) = self.items.get(&TypeId::of::<S>())?;
+
+        value.clone().downcast::<S>().ok()
+    }
+
+    pub fn contains(&self, key: &StateKey) -> bool {
+        self.items.contains_key(&key.id)
+    }
+}
+
+impl Debug for States {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list()
+            .entries(self.items.values().map(|(key, _)| key.name))
+            .finish()
+    }
+}
@@ -0,0 +1,53 @@
+use std::{net::SocketAddr, sync::Arc};
+
+use serde::{Deserialize, Serialize};
+
+/// Kind of transport that delivered the request
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum TransportKind {
+    Http,
+    WebSocket,
+    Tcp,
+    Ipc,
+    /// Request was not delivered by network, eg. in tests
+    Local,
+}
+
+/// Context of connection supplied by transport to every processed request
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct TransportContext {
+    pub kind: TransportKind,
+    /// Remote address of the peer
+    pub remote_addr: Option<SocketAddr>,
+    /// Subject of the TLS client certificate
+    pub tls_client_subject: Option<String>,
+}
+
+pub type TransportContextRef = Arc<TransportContext>;
+
+impl TransportContext {
+    pub fn new(kind: TransportKind) -> Self {
+        Self {
+            kind,
+            remote_addr: None,
+            tls_client_subject: None,
+        }
+    }
+
+    /// Context for requests that are not delivered by network
+    pub fn local() -> Self {
+        Self::new(TransportKind::Local)
+    }
+
+    pub fn with_remote_addr(mut self, remote_addr: SocketAddr) -> Self {
+        self.remote_addr = Some(remote_addr);
+        self
+    }
+
+    pub fn with_tls_client_subject(mut self, subject: &str) -> Self {
+        self.tls_client_subject = Some(subject.to_string());
+        self
+    }
+}
@@ -0,0 +1,54 @@
+/// Deprecation of the item, `Some` with optional note if it has `#[deprecated]` attribute
+pub fn find_deprecation_in_attrs(attrs: &[syn::Attribute]) -> Option<Option<String>> {
+    let attr = attrs.iter().find(|attr| attr.path().is_ident("deprecated"))?;
+
+    let note = match &attr.meta {
+        // #[deprecated]
+        syn::Meta::Path(_) => None,
+        // #[deprecated = "note"]
+        syn::Meta::NameValue(syn::MetaNameValue {
+            value: syn::Expr::Lit(syn::ExprLit {
+                lit: syn::Lit::Str(lit_str),
+                ..
+            }),
+            ..
+        }) => Some(lit_str.value()),
+        syn::Meta::NameValue(_) => None,
+        // #[deprecated(since = "1.0.0", note = "note")]
+        syn::Meta::List(_) => {
+            let mut note = None;
+
+            let _ = attr.parse_nested_meta(|meta| {
+                let value = meta.value()?.parse::<syn::LitStr>()?;
+
+                if meta.path.is_ident("note") {
+                    note = Some(value.value());
+                }
+
+                Ok(())
+            });
+
+            note
+        }
+    };
+
+    Some(note)
+}
+
+/// Tokens of `deprecated` and `deprecation_note` fields of `SchemaField`
+pub fn deprecation_tokens(deprecation: &Option<Option<String>>) -> proc_macro2::TokenStream {
+    match deprecation {
+        Some(Some(note)) => quote::quote! {
+            deprecated: true,
+            deprecation_note: Some(String::from(#note)),
+        },
+        Some(None) => quote::quote! {
+            deprecated: true,
+            deprecation_note: None,
+        },
+        None => quote::quote! {
+            deprecated: false,
+            deprecation_note: None,
+        },
+    }
+}
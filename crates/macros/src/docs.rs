@@ -0,0 +1,45 @@
+/// Collect doc comments (`///`) of the item into one description
+pub fn find_doc_in_attrs(attrs: &[syn::Attribute]) -> Option<String> {
+    let mut lines = Vec::new();
+
+    for attr in attrs {
+        if !attr.path().is_ident("doc") {
+            continue;
+        }
+
+        let line = match &attr.meta {
+            syn::Meta::NameValue(syn::MetaNameValue {
+                value: syn::Expr::Lit(syn::ExprLit {
+                    lit: syn::Lit::Str(lit_str),
+                    ..
+                }),
+                ..
+            }) => lit_str.value(),
+            _ => continue,
+        };
+
+        // Doc comments start with space after `///`
+        let line = match line.strip_prefix(' ') {
+            Some(line) => line.to_string(),
+            None => line,
+        };
+
+        lines.push(line.trim_end().to_string());
+    }
+
+    let description = lines.join("\n").trim().to_string();
+
+    if description.is_empty() {
+        return None;
+    }
+
+    Some(description)
+}
+
+/// Tokens of optional description
+pub fn description_tokens(description: &Option<String>) -> proc_macro2::TokenStream {
+    match description {
+        Some(description) => quote::quote! { Some(String::from(#description)) },
+        None => quote::quote! { None },
+    }
+}
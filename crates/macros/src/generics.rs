@@ -0,0 +1,76 @@
+/// Generics of schema impls, every type parameter must be schemable
+pub fn schemable_generics(generics: &syn::Generics) -> syn::Generics {
+    let mut generics = generics.clone();
+    let params = generics.type_params().map(|param| param.ident.clone()).collect::<Vec<_>>();
+
+    let where_clause = generics.make_where_clause();
+    for param in params {
+        where_clause
+            .predicates
+            .push(syn::parse_quote!(#param: rpc::open_schema::SchemableField));
+    }
+
+    generics
+}
+
+/// Add bounds of generic fields to generics of schema impls, non-generic type needs no bounds
+pub fn with_predicates(mut generics: syn::Generics, predicates: Vec<syn::WherePredicate>) -> syn::Generics {
+    if generics.type_params().next().is_some() {
+        generics.make_where_clause().predicates.extend(predicates);
+    }
+
+    generics
+}
+
+/// Generics of `Responder` impl, the type must be serializable
+pub fn responder_generics(generics: &syn::Generics) -> syn::Generics {
+    let mut generics = generics.clone();
+
+    if !generics.params.is_empty() {
+        generics
+            .make_where_clause()
+            .predicates
+            .push(syn::parse_quote!(Self: rpc::serde::Serialize));
+    }
+
+    generics
+}
+
+/// Body of `type_name` function, every instance of generic type has its own name, eg. `Page_User`
+pub fn type_name_tokens(ident: &syn::Ident, generics: &syn::Generics) -> proc_macro2::TokenStream {
+    let str_ident = ident.to_string();
+
+    let params = generics
+        .params
+        .iter()
+        .filter_map(|param| match param {
+            syn::GenericParam::Type(param) => {
+                let param = &param.ident;
+
+                Some(quote::quote! {
+                    name.push('_');
+                    name.push_str(&<#param as rpc::open_schema::SchemableField>::get_rel_type().type_name());
+                })
+            }
+            syn::GenericParam::Const(param) => {
+                let param = &param.ident;
+
+                Some(quote::quote! {
+                    name.push('_');
+                    name.push_str(&#param.to_string());
+                })
+            }
+            syn::GenericParam::Lifetime(_) => None,
+        })
+        .collect::<Vec<_>>();
+
+    if params.is_empty() {
+        return quote::quote! { String::from(#str_ident) };
+    }
+
+    quote::quote! {
+        let mut name = String::from(#str_ident);
+        #(#params)*
+        name
+    }
+}
@@ -1,9 +1,15 @@
 use proc_macro::TokenStream;
 
+pub(crate) mod deprecation;
+pub(crate) mod docs;
+pub(crate) mod generics;
 pub(crate) mod rename;
+pub(crate) mod rpc_error;
 pub(crate) mod type_enum;
 pub(crate) mod type_named_struct;
-// pub(crate) mod type_unnamed_struct;
+pub(crate) mod type_union;
+pub(crate) mod type_unnamed_struct;
+pub(crate) mod values;
 
 /// Procedural macro 'Schemable' that implements multiple traits for a struct and enum
 /// that is used to build schema for client-server communication.
@@ -19,6 +25,26 @@ pub(crate) mod type_named_struct;
 /// }
 ///
 /// #[derive(rpc::Schemable, serde::Serialize, serde::Deserialize)]
+/// struct User {
+///    #[rpc(example = "alice")]
+///    pub name: String,
+///    #[serde(default)]
+///    pub admin: bool,
+/// }
+///
+/// #[derive(rpc::Schemable, serde::Serialize, serde::Deserialize)]
+/// struct Page<T> {
+///    pub items: Vec<T>,
+///    pub next: Option<String>,
+/// }
+///
+/// #[derive(rpc::Schemable, serde::Serialize, serde::Deserialize)]
+/// struct UserId(Uuid);
+///
+/// #[derive(rpc::Schemable, serde::Serialize, serde::Deserialize)]
+/// struct Point(i32, i32);
+///
+/// #[derive(rpc::Schemable, serde::Serialize, serde::Deserialize)]
 /// enum FooEnum {
 ///    Bar,
 ///    Baz,
@@ -29,34 +55,107 @@ pub(crate) mod type_named_struct;
 ///    Bar = 1,
 ///    Baz = 2,
 /// }
+///
+/// #[derive(rpc::Schemable, serde::Serialize, serde::Deserialize)]
+/// #[serde(tag = "type", content = "data")]
+/// enum Event {
+///    Created { id: u32 },
+///    Renamed(String),
+///    Deleted,
+/// }
 /// ```
-#[proc_macro_derive(Schemable)]
+#[proc_macro_derive(Schemable, attributes(rpc))]
 pub fn derive_schemable(input: TokenStream) -> TokenStream {
     // Construct a representation of Rust code as a syntax tree
     // that we can manipulate
     let ast: syn::DeriveInput = syn::parse(input).unwrap();
     let ident = &ast.ident;
 
-    let rename_all = rename::find_type_in_attr(ast.attrs, "rename_all");
+    let description = docs::find_doc_in_attrs(&ast.attrs);
+    let container = match rename::find_container_attrs(&ast.attrs) {
+        Ok(container) => container,
+        Err(e) => return e.to_compile_error().into(),
+    };
 
     match ast.data {
+        // Transparent struct is serialized as its only field
+        syn::Data::Struct(syn::DataStruct {
+            fields: syn::Fields::Named(syn::FieldsNamed { named, .. }),
+            ..
+        }) if container.transparent => {
+            type_unnamed_struct::implement(ident.clone(), ast.generics, named, true, description)
+        }
         syn::Data::Struct(syn::DataStruct {
             fields: syn::Fields::Named(syn::FieldsNamed { named, .. }),
             ..
-        }) => type_named_struct::implement(ident.clone(), named.clone(), rename_all),
-        // syn::Data::Struct(syn::DataStruct {
-        //     fields: syn::Fields::Unnamed(syn::FieldsUnnamed { unnamed, .. }),
-        //     ..
-        // }) => {
-        //     type_unnamed_struct::implement(ident.clone(), unnamed.clone());
-        //     panic!("Unnamed fields are not supported")
-        // }
+        }) => type_named_struct::implement(ident.clone(), ast.generics, named, container, description),
+        syn::Data::Struct(syn::DataStruct {
+            fields: syn::Fields::Unnamed(syn::FieldsUnnamed { unnamed, .. }),
+            ..
+        }) => type_unnamed_struct::implement(
+            ident.clone(),
+            ast.generics,
+            unnamed.clone(),
+            container.transparent,
+            description,
+        ),
         syn::Data::Enum(enum_item) => {
             let enum_fields = enum_item.variants.into_iter().collect::<Vec<_>>();
 
-            type_enum::implement(ident.clone(), enum_fields)
+            // Enum with data or with tagged unit variants is union, C-like enum is plain enum
+            let is_union = !matches!(container.tagging, rename::Tagging::External)
+                || enum_fields
+                    .iter()
+                    .any(|variant| !matches!(variant.fields, syn::Fields::Unit));
+
+            if is_union {
+                type_union::implement(ident.clone(), ast.generics, enum_fields, container, description)
+            } else {
+                type_enum::implement(ident.clone(), enum_fields, container, description)
+            }
+        }
+        _ => panic!("Only named structs, tuple structs and enums are supported"),
+    }
+}
+
+/// Procedural macro 'RpcError' that implements typed procedure error for an enum.
+/// Every variant declares its error code and HTTP code (500 by default),
+/// newtype variants carry typed payload. Error catalogue is part of the schema of procedures
+/// that declare the error by `Procedure::errors`.
+///
+/// The macro implements `serde::Serialize` as `{ "code", "httpCode", "payload" }`, so the enum
+/// must not derive `Serialize` too, otherwise the compiler reports conflicting implementations.
+/// Generated code uses serde re-exported by `rpc`, the crate does not need to depend on serde.
+///
+/// # Example
+///
+/// ```ignore
+/// #[derive(rpc::RpcError)]
+/// enum UserError {
+///    #[rpc_error(code = "USER_NOT_FOUND", http = 404)]
+///    NotFound,
+///    #[rpc_error(code = "USER_INVALID", http = 400)]
+///    Invalid(InvalidUser),
+/// }
+///
+/// async fn get_user(args: Args<UserId>) -> Result<User, UserError> { ... }
+///
+/// router.add_query(get_user).errors::<UserError>();
+/// ```
+#[proc_macro_derive(RpcError, attributes(rpc_error))]
+pub fn derive_rpc_error(input: TokenStream) -> TokenStream {
+    let ast: syn::DeriveInput = syn::parse(input).unwrap();
+    let ident = &ast.ident;
+
+    let description = docs::find_doc_in_attrs(&ast.attrs);
+
+    match ast.data {
+        syn::Data::Enum(enum_item) => {
+            let enum_variants = enum_item.variants.into_iter().collect::<Vec<_>>();
+
+            rpc_error::implement(ident.clone(), enum_variants, description)
         }
-        _ => panic!("Only named structs and enums are supported"),
+        _ => panic!("Only enums are supported"),
     }
 }
 
@@ -1,63 +1,407 @@
-pub enum RenameType {
+use syn::meta::ParseNestedMeta;
+
+/// Rule of `#[serde(rename_all = "...")]`, applied the same way as serde does
+#[derive(Clone, Copy)]
+pub enum RenameRule {
     None,
+    LowerCase,
+    UpperCase,
+    PascalCase,
     CamelCase,
+    SnakeCase,
+    ScreamingSnakeCase,
+    KebabCase,
+    ScreamingKebabCase,
 }
 
-pub fn find_type_in_attr<I>(attrs: Vec<syn::Attribute>, ident: &I) -> RenameType
-where
-    I: ?Sized + AsRef<str>,
-{
-    if attrs.is_empty() {
-        return RenameType::None;
+impl RenameRule {
+    /// Parse rule of `rename_all = "..."`, unknown rule is reported at the attribute
+    fn parse(meta: &ParseNestedMeta, value: &str) -> syn::Result<Self> {
+        let rule = match value {
+            "lowercase" => Self::LowerCase,
+            "UPPERCASE" => Self::UpperCase,
+            "PascalCase" => Self::PascalCase,
+            "camelCase" => Self::CamelCase,
+            "snake_case" => Self::SnakeCase,
+            "SCREAMING_SNAKE_CASE" => Self::ScreamingSnakeCase,
+            "kebab-case" => Self::KebabCase,
+            "SCREAMING-KEBAB-CASE" => Self::ScreamingKebabCase,
+            _ => return Err(meta.error(format!("Unsupported rename rule `{value}`"))),
+        };
+
+        Ok(rule)
     }
 
-    let mut rename_all = RenameType::None;
+    /// Rename field, field is expected in snake_case
+    pub fn apply_to_field(&self, field: &str) -> String {
+        let field = unraw(field);
 
-    for atrr in attrs {
-        if atrr.path().is_ident("serde") {
-            // from atrr try get rename_all
-            let expr: syn::Expr = atrr.parse_args().unwrap();
+        match self {
+            Self::None | Self::LowerCase | Self::SnakeCase => field.to_string(),
+            Self::UpperCase | Self::ScreamingSnakeCase => field.to_ascii_uppercase(),
+            Self::PascalCase => {
+                let mut pascal = String::new();
+                let mut capitalize = true;
 
-            let assign = match expr {
-                syn::Expr::Assign(assign) => Some(assign),
-                _ => None,
-            };
+                for ch in field.chars() {
+                    if ch == '_' {
+                        capitalize = true;
+                    } else if capitalize {
+                        pascal.push(ch.to_ascii_uppercase());
+                        capitalize = false;
+                    } else {
+                        pascal.push(ch);
+                    }
+                }
 
-            if assign.is_none() {
-                continue;
+                pascal
             }
+            Self::CamelCase => lowercase_first(&Self::PascalCase.apply_to_field(field)),
+            Self::KebabCase => field.replace('_', "-"),
+            Self::ScreamingKebabCase => Self::ScreamingSnakeCase.apply_to_field(field).replace('_', "-"),
+        }
+    }
 
-            let assign = assign.unwrap();
+    /// Rename variant, variant is expected in PascalCase
+    pub fn apply_to_variant(&self, variant: &str) -> String {
+        let variant = unraw(variant);
 
-            let left = assign.left.clone();
-            let left = *left;
+        match self {
+            Self::None | Self::PascalCase => variant.to_string(),
+            Self::LowerCase => variant.to_ascii_lowercase(),
+            Self::UpperCase => variant.to_ascii_uppercase(),
+            Self::CamelCase => lowercase_first(variant),
+            Self::SnakeCase => {
+                let mut snake = String::new();
 
-            let is_rename_all = match left {
-                syn::Expr::Path(left) => left.path.is_ident(ident),
-                _ => false,
-            };
+                for (i, ch) in variant.char_indices() {
+                    if i > 0 && ch.is_uppercase() {
+                        snake.push('_');
+                    }
 
-            let right = assign.right.clone();
-            let right = *right;
+                    snake.push(ch.to_ascii_lowercase());
+                }
 
-            match (is_rename_all, right) {
-                (true, syn::Expr::Lit(expr_lit)) => match expr_lit.lit {
-                    syn::Lit::Str(lit_str) => {
-                        let value = lit_str.value();
+                snake
+            }
+            Self::ScreamingSnakeCase => Self::SnakeCase.apply_to_variant(variant).to_ascii_uppercase(),
+            Self::KebabCase => Self::SnakeCase.apply_to_variant(variant).replace('_', "-"),
+            Self::ScreamingKebabCase => Self::ScreamingSnakeCase.apply_to_variant(variant).replace('_', "-"),
+        }
+    }
+}
 
-                        match value.as_str() {
-                            "camelCase" => {
-                                rename_all = RenameType::CamelCase;
-                            }
-                            _ => {}
-                        }
-                    }
-                    _ => {}
-                },
-                _ => {}
+/// Name of raw identifier without `r#` prefix, eg. `r#type` is `type`
+fn unraw(name: &str) -> &str {
+    name.strip_prefix("r#").unwrap_or(name)
+}
+
+/// Lowercase first character, identifier may start with non-ASCII character
+fn lowercase_first(name: &str) -> String {
+    let mut chars = name.chars();
+
+    match chars.next() {
+        Some(first) => first.to_ascii_lowercase().to_string() + chars.as_str(),
+        None => String::new(),
+    }
+}
+
+/// Serde representation of enum variants
+pub enum Tagging {
+    /// `{"Variant": payload}`
+    External,
+    /// `#[serde(tag = "...")]`
+    Internal { tag: String },
+    /// `#[serde(tag = "...", content = "...")]`
+    Adjacent { tag: String, content: String },
+    /// `#[serde(untagged)]`
+    Untagged,
+}
+
+impl Tagging {
+    /// Tokens of `SchemaTagging`
+    pub fn to_tokens(&self) -> proc_macro2::TokenStream {
+        match self {
+            Tagging::External => quote::quote! {
+                rpc::open_schema::schema::SchemaTagging::External
+            },
+            Tagging::Internal { tag } => quote::quote! {
+                rpc::open_schema::schema::SchemaTagging::Internal {
+                    tag: String::from(#tag),
+                }
+            },
+            Tagging::Adjacent { tag, content } => quote::quote! {
+                rpc::open_schema::schema::SchemaTagging::Adjacent {
+                    tag: String::from(#tag),
+                    content: String::from(#content),
+                }
+            },
+            Tagging::Untagged => quote::quote! {
+                rpc::open_schema::schema::SchemaTagging::Untagged
+            },
+        }
+    }
+}
+
+/// Serde attributes of struct or enum
+pub struct ContainerAttrs {
+    /// Rule of field names of struct, variant names of enum
+    pub rename_all: RenameRule,
+    /// Rule of field names of struct variants
+    pub rename_all_fields: RenameRule,
+    pub tagging: Tagging,
+    /// Struct is serialized as its only field
+    pub transparent: bool,
+    /// Missing fields are filled by default value
+    pub default: bool,
+}
+
+/// Default value of missing field
+pub enum FieldDefault {
+    /// `#[serde(default)]`
+    Trait,
+    /// `#[serde(default = "path")]`
+    Path(syn::ExprPath),
+}
+
+/// Serde attributes of struct field
+pub struct FieldAttrs {
+    pub rename: Option<String>,
+    pub aliases: Vec<String>,
+    /// Field is not serialized nor deserialized
+    pub skip: bool,
+    /// Field can be missing, eg. it has default value or it is skipped by condition
+    pub optional: bool,
+    /// Fields of the field are serialized into the parent
+    pub flatten: bool,
+    /// Default value, only if the field is serialized as its own type
+    pub default: Option<FieldDefault>,
+}
+
+/// Serde attributes of enum variant
+pub struct VariantAttrs {
+    pub rename: Option<String>,
+    pub aliases: Vec<String>,
+    /// Variant is not serialized nor deserialized
+    pub skip: bool,
+    /// Rule of field names of struct variant
+    pub rename_all: RenameRule,
+}
+
+pub fn find_container_attrs(attrs: &[syn::Attribute]) -> syn::Result<ContainerAttrs> {
+    let mut rename_all = RenameRule::None;
+    let mut rename_all_fields = RenameRule::None;
+    let mut tag = None;
+    let mut content = None;
+    let mut untagged = false;
+    let mut transparent = false;
+    let mut default = false;
+    let mut error = None;
+
+    parse_serde_attrs(attrs, |meta| {
+        if meta.path.is_ident("rename_all") {
+            if let Some(rule) = parse_name(&meta)? {
+                match RenameRule::parse(&meta, &rule) {
+                    Ok(rule) => rename_all = rule,
+                    Err(e) => error = Some(e),
+                }
+            }
+        } else if meta.path.is_ident("rename_all_fields") {
+            if let Some(rule) = parse_name(&meta)? {
+                match RenameRule::parse(&meta, &rule) {
+                    Ok(rule) => rename_all_fields = rule,
+                    Err(e) => error = Some(e),
+                }
+            }
+        } else if meta.path.is_ident("tag") {
+            tag = Some(parse_str(&meta)?);
+        } else if meta.path.is_ident("content") {
+            content = Some(parse_str(&meta)?);
+        } else if meta.path.is_ident("untagged") {
+            untagged = true;
+        } else if meta.path.is_ident("transparent") {
+            transparent = true;
+        } else if meta.path.is_ident("default") {
+            default = true;
+            skip_meta(&meta)?;
+        } else {
+            skip_meta(&meta)?;
+        }
+
+        Ok(())
+    });
+
+    // Unknown rule is reported, other invalid attributes are reported by serde derive
+    if let Some(error) = error {
+        return Err(error);
+    }
+
+    let tagging = match (untagged, tag, content) {
+        (true, _, _) => Tagging::Untagged,
+        (false, Some(tag), Some(content)) => Tagging::Adjacent { tag, content },
+        (false, Some(tag), None) => Tagging::Internal { tag },
+        (false, None, _) => Tagging::External,
+    };
+
+    Ok(ContainerAttrs {
+        rename_all,
+        rename_all_fields,
+        tagging,
+        transparent,
+        default,
+    })
+}
+
+pub fn find_field_attrs(attrs: &[syn::Attribute]) -> FieldAttrs {
+    let mut rename = None;
+    let mut aliases = Vec::new();
+    let mut skip = false;
+    let mut optional = false;
+    let mut flatten = false;
+    let mut default = None;
+    let mut custom_serialize = false;
+
+    parse_serde_attrs(attrs, |meta| {
+        if meta.path.is_ident("rename") {
+            rename = parse_name(&meta)?;
+        } else if meta.path.is_ident("alias") {
+            aliases.push(parse_str(&meta)?);
+        } else if meta.path.is_ident("skip") {
+            skip = true;
+        } else if meta.path.is_ident("default") {
+            optional = true;
+            default = if meta.input.peek(syn::Token![=]) {
+                Some(FieldDefault::Path(syn::parse_str(&parse_str(&meta)?)?))
+            } else {
+                Some(FieldDefault::Trait)
             };
+        } else if meta.path.is_ident("with") || meta.path.is_ident("serialize_with") {
+            // Field is not serialized as its own type
+            custom_serialize = true;
+            skip_meta(&meta)?;
+        } else if meta.path.is_ident("skip_serializing") {
+            // Field type does not have to be serializable
+            optional = true;
+            custom_serialize = true;
+        } else if meta.path.is_ident("skip_deserializing") || meta.path.is_ident("skip_serializing_if") {
+            // Field is missing in one direction or it can be omitted
+            optional = true;
+            skip_meta(&meta)?;
+        } else if meta.path.is_ident("flatten") {
+            flatten = true;
+        } else {
+            skip_meta(&meta)?;
+        }
+
+        Ok(())
+    });
+
+    FieldAttrs {
+        rename,
+        aliases,
+        skip,
+        optional,
+        flatten,
+        default: default.filter(|_| !custom_serialize),
+    }
+}
+
+pub fn find_variant_attrs(attrs: &[syn::Attribute]) -> syn::Result<VariantAttrs> {
+    let mut rename = None;
+    let mut aliases = Vec::new();
+    let mut skip = false;
+    let mut rename_all = RenameRule::None;
+    let mut error = None;
+
+    parse_serde_attrs(attrs, |meta| {
+        if meta.path.is_ident("rename") {
+            rename = parse_name(&meta)?;
+        } else if meta.path.is_ident("alias") {
+            aliases.push(parse_str(&meta)?);
+        } else if meta.path.is_ident("skip") || meta.path.is_ident("skip_serializing") {
+            skip = true;
+        } else if meta.path.is_ident("rename_all") {
+            if let Some(rule) = parse_name(&meta)? {
+                match RenameRule::parse(&meta, &rule) {
+                    Ok(rule) => rename_all = rule,
+                    Err(e) => error = Some(e),
+                }
+            }
+        } else {
+            skip_meta(&meta)?;
+        }
+
+        Ok(())
+    });
+
+    // Unknown rule is reported, other invalid attributes are reported by serde derive
+    if let Some(error) = error {
+        return Err(error);
+    }
+
+    Ok(VariantAttrs {
+        rename,
+        aliases,
+        skip,
+        rename_all,
+    })
+}
+
+/// Tokens of list of aliases
+pub fn aliases_tokens(aliases: &[String]) -> proc_macro2::TokenStream {
+    quote::quote! { vec![#(String::from(#aliases)),*] }
+}
+
+/// Call `parse` for every item of `#[serde(...)]` attributes
+fn parse_serde_attrs<F>(attrs: &[syn::Attribute], mut parse: F)
+where
+    F: FnMut(ParseNestedMeta) -> syn::Result<()>,
+{
+    for attr in attrs {
+        if !attr.path().is_ident("serde") {
+            continue;
+        }
+
+        // Invalid attributes are reported by serde derive
+        let _ = attr.parse_nested_meta(&mut parse);
+    }
+}
+
+/// Parse `name = "value"`
+fn parse_str(meta: &ParseNestedMeta) -> syn::Result<String> {
+    Ok(meta.value()?.parse::<syn::LitStr>()?.value())
+}
+
+/// Parse `name = "value"` or `name(serialize = "value", deserialize = "...")`,
+/// only serialized name is used.
+fn parse_name(meta: &ParseNestedMeta) -> syn::Result<Option<String>> {
+    if meta.input.peek(syn::Token![=]) {
+        return parse_str(meta).map(Some);
+    }
+
+    let mut name = None;
+
+    meta.parse_nested_meta(|meta| {
+        if meta.path.is_ident("serialize") {
+            name = Some(parse_str(&meta)?);
+        } else {
+            skip_meta(&meta)?;
         }
+
+        Ok(())
+    })?;
+
+    Ok(name)
+}
+
+/// Skip value or list of the attribute that does not change schema, eg. `with = "..."` or `bound(...)`
+fn skip_meta(meta: &ParseNestedMeta) -> syn::Result<()> {
+    if meta.input.peek(syn::Token![=]) {
+        meta.value()?.parse::<syn::Expr>()?;
+    } else if meta.input.peek(syn::token::Paren) {
+        let _list;
+        syn::parenthesized!(_list in meta.input);
     }
 
-    rename_all
+    Ok(())
 }
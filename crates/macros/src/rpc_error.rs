@@ -0,0 +1,273 @@
+use proc_macro::TokenStream;
+use syn::Variant;
+
+use crate::{deprecation, docs};
+
+/// Struct that represents a variant of an error enum
+struct ErrorVariant {
+    /// Name of the variant
+    ident: syn::Ident,
+    /// Error code of the variant
+    code: String,
+    /// HTTP code of the variant
+    http_code: u16,
+    /// Type of the payload, for newtype variants
+    payload: Option<syn::Type>,
+    /// Doc comment of the variant
+    description: Option<String>,
+    /// Deprecation of the variant, with optional note
+    deprecation: Option<Option<String>>,
+}
+
+/// Default HTTP code of the error variant
+const DEFAULT_HTTP_CODE: u16 = 500;
+
+pub fn implement(ident: syn::Ident, enum_variants: Vec<Variant>, description: Option<String>) -> TokenStream {
+    let mut properties = Vec::new();
+
+    for variant in enum_variants {
+        let payload = match variant.fields {
+            syn::Fields::Unit => None,
+            syn::Fields::Unnamed(syn::FieldsUnnamed { unnamed, .. }) if unnamed.len() == 1 => {
+                Some(unnamed.first().unwrap().ty.clone())
+            }
+            _ => panic!("Only unit and newtype variants are supported in error enum"),
+        };
+
+        let mut code = None;
+        let mut http_code = DEFAULT_HTTP_CODE;
+
+        for attr in &variant.attrs {
+            if !attr.path().is_ident("rpc_error") {
+                continue;
+            }
+
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("code") {
+                    let value: syn::LitStr = meta.value()?.parse()?;
+                    code = Some(value.value());
+                } else if meta.path.is_ident("http") {
+                    let value: syn::LitInt = meta.value()?.parse()?;
+                    http_code = value.base10_parse()?;
+                } else {
+                    return Err(meta.error("Unsupported rpc_error attribute"));
+                }
+
+                Ok(())
+            })
+            .unwrap();
+        }
+
+        let code = code.unwrap_or_else(|| panic!("Variant `{}` has no error code", variant.ident));
+
+        properties.push(ErrorVariant {
+            ident: variant.ident.clone(),
+            code,
+            http_code,
+            payload,
+            description: docs::find_doc_in_attrs(&variant.attrs),
+            deprecation: deprecation::find_deprecation_in_attrs(&variant.attrs),
+        });
+    }
+
+    let str_ident = ident.to_string();
+    let description = docs::description_tokens(&description);
+
+    let mut codes = Vec::new();
+    let mut http_codes = Vec::new();
+    let mut serializers = Vec::new();
+    let mut schemas = Vec::new();
+    let mut fields = Vec::new();
+
+    for property in properties {
+        let variant = property.ident;
+        let str_variant = variant.to_string();
+        let code = property.code;
+        let http_code = property.http_code;
+        let field_description = docs::description_tokens(&property.description);
+        let field_deprecation = deprecation::deprecation_tokens(&property.deprecation);
+
+        match property.payload {
+            Some(ty) => {
+                codes.push(quote::quote! { Self::#variant(_) => #code, });
+                http_codes.push(quote::quote! { Self::#variant(_) => #http_code, });
+
+                serializers.push(quote::quote! {
+                    Self::#variant(payload) => {
+                        let mut state = serializer.serialize_struct(#str_ident, 3)?;
+                        state.serialize_field("code", #code)?;
+                        state.serialize_field("httpCode", &#http_code)?;
+                        state.serialize_field("payload", payload)?;
+                        state.end()
+                    }
+                });
+
+                schemas.push(quote::quote! {
+                    let mut field = rpc::open_schema::schema::SchemaField {
+                        name: String::from("payload"),
+                        rel: None,
+                        value: None,
+                        description: None,
+                        deprecated: false,
+                        deprecation_note: None,
+                        optional: false,
+                        flatten: false,
+                        aliases: Vec::new(),
+                        example: None,
+                        default: None,
+                    };
+
+                    <#ty as rpc::open_schema::SchemableField>::explore_type(&mut field, type_map.clone());
+
+                    proc.errors.push(rpc::open_schema::schema::SchemaError {
+                        name: String::from(#str_ident),
+                        variant: String::from(#str_variant),
+                        code: String::from(#code),
+                        http_code: #http_code,
+                        payload: field.rel,
+                    });
+                });
+
+                fields.push(quote::quote! {
+                    let mut field = rpc::open_schema::schema::SchemaField {
+                        name: String::from(#str_variant),
+                        rel: None,
+                        value: Some(String::from(#code)),
+                        description: #field_description,
+                        #field_deprecation
+                        optional: false,
+                        flatten: false,
+                        aliases: Vec::new(),
+                        example: None,
+                        default: None,
+                    };
+
+                    <#ty as rpc::open_schema::SchemableField>::explore_type(&mut field, type_map.clone());
+
+                    fields.push(field);
+                });
+            }
+            None => {
+                codes.push(quote::quote! { Self::#variant => #code, });
+                http_codes.push(quote::quote! { Self::#variant => #http_code, });
+
+                serializers.push(quote::quote! {
+                    Self::#variant => {
+                        let mut state = serializer.serialize_struct(#str_ident, 2)?;
+                        state.serialize_field("code", #code)?;
+                        state.serialize_field("httpCode", &#http_code)?;
+                        state.end()
+                    }
+                });
+
+                schemas.push(quote::quote! {
+                    proc.errors.push(rpc::open_schema::schema::SchemaError {
+                        name: String::from(#str_ident),
+                        variant: String::from(#str_variant),
+                        code: String::from(#code),
+                        http_code: #http_code,
+                        payload: None,
+                    });
+                });
+
+                fields.push(quote::quote! {
+                    let field = rpc::open_schema::schema::SchemaField {
+                        name: String::from(#str_variant),
+                        rel: None,
+                        value: Some(String::from(#code)),
+                        description: #field_description,
+                        #field_deprecation
+                        optional: false,
+                        flatten: false,
+                        aliases: Vec::new(),
+                        example: None,
+                        default: None,
+                    };
+
+                    fields.push(field);
+                });
+            }
+        }
+    }
+
+    // Deprecated variants are matched by generated code
+    let gen = quote::quote! {
+        #[allow(deprecated)]
+        impl rpc::RpcError for #ident {
+            fn code(&self) -> &'static str {
+                match self {
+                    #(#codes)*
+                }
+            }
+
+            fn http_code(&self) -> u16 {
+                match self {
+                    #(#http_codes)*
+                }
+            }
+        }
+
+        #[allow(deprecated)]
+        impl rpc::serde::Serialize for #ident {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: rpc::serde::Serializer,
+            {
+                use rpc::serde::ser::SerializeStruct;
+
+                match self {
+                    #(#serializers)*
+                }
+            }
+        }
+
+        impl rpc::open_schema::SchemableType for #ident {
+            #[inline]
+            fn schema_type() -> rpc::open_schema::schema::SchemaTypes {
+                rpc::open_schema::schema::SchemaTypes::Error
+            }
+
+            #[inline]
+            fn type_name() -> String {
+                String::from(#str_ident)
+            }
+
+            #[inline]
+            fn type_description() -> Option<String> {
+                #description
+            }
+
+            #[allow(unused_variables)]
+            fn type_fields(
+                type_map: rpc::open_schema::schema::TypeMapRef,
+            ) -> Vec<rpc::open_schema::schema::SchemaField> {
+                let mut fields = Vec::new();
+
+                #({ #fields })*
+
+                fields
+            }
+        }
+
+        impl rpc::open_schema::SchemableError for #ident {
+            fn apply_schema(
+                proc: &mut rpc::open_schema::schema::SchemaProcedure,
+                type_map: rpc::open_schema::schema::TypeMapRef,
+            ) {
+                proc.error = Some(rpc::open_schema::schema::SchemaFieldRel::Error {
+                    name: String::from(#str_ident),
+                });
+
+                // Insert error into type map
+                rpc::open_schema::schema::insert_into_type_map_ref::<#ident>(
+                    rpc::open_schema::schema::SchemaTypes::Error,
+                    type_map.clone(),
+                );
+
+                #({ #schemas })*
+            }
+        }
+    };
+
+    gen.into()
+}
@@ -1,16 +1,37 @@
 use proc_macro::TokenStream;
 use syn::Variant;
 
-#[derive(Debug)]
+use crate::{deprecation, docs, rename};
+
 struct EnumVariant {
     ident: syn::Ident,
+    /// Serialized name of the variant
+    name: String,
     expr: Option<syn::Expr>,
+    description: Option<String>,
+    deprecation: Option<Option<String>>,
+    aliases: Vec<String>,
 }
 
-pub fn implement(ident: syn::Ident, enum_variants: Vec<Variant>) -> TokenStream {
+pub fn implement(
+    ident: syn::Ident,
+    enum_variants: Vec<Variant>,
+    container: rename::ContainerAttrs,
+    description: Option<String>,
+) -> TokenStream {
     let mut properties = Vec::new();
 
     for variant in enum_variants {
+        let attrs = match rename::find_variant_attrs(&variant.attrs) {
+            Ok(attrs) => attrs,
+            Err(e) => return e.to_compile_error().into(),
+        };
+
+        // Skipped variant is not part of the wire format
+        if attrs.skip {
+            continue;
+        }
+
         match variant.fields {
             syn::Fields::Named(_) => {
                 panic!("Named fields in enum is not supported");
@@ -21,8 +42,17 @@ pub fn implement(ident: syn::Ident, enum_variants: Vec<Variant>) -> TokenStream
             _ => {}
         }
 
+        let name = match attrs.rename {
+            Some(rename) => rename,
+            None => container.rename_all.apply_to_variant(&variant.ident.to_string()),
+        };
+
         let field = EnumVariant {
             ident: variant.ident.clone(),
+            name,
+            aliases: attrs.aliases,
+            description: docs::find_doc_in_attrs(&variant.attrs),
+            deprecation: deprecation::find_deprecation_in_attrs(&variant.attrs),
             expr: {
                 if variant.discriminant.is_some() {
                     let discriminant = variant.discriminant.unwrap();
@@ -49,6 +79,9 @@ pub fn implement(ident: syn::Ident, enum_variants: Vec<Variant>) -> TokenStream
 
     for property in properties {
         let ident = property.ident;
+        let field_description = docs::description_tokens(&property.description);
+        let field_deprecation = deprecation::deprecation_tokens(&property.deprecation);
+        let aliases = rename::aliases_tokens(&property.aliases);
 
         let mut field_code = quote::quote! {};
 
@@ -93,7 +126,7 @@ pub fn implement(ident: syn::Ident, enum_variants: Vec<Variant>) -> TokenStream
                 let value = Some(String::from(#str_value));
             };
         } else {
-            let str_ident = ident.to_string();
+            let str_ident = property.name;
 
             field_code = quote::quote! {
                 #field_code
@@ -113,6 +146,13 @@ pub fn implement(ident: syn::Ident, enum_variants: Vec<Variant>) -> TokenStream
                     format,
                 }),
                 value,
+                description: #field_description,
+                #field_deprecation
+                optional: false,
+                flatten: false,
+                aliases: #aliases,
+                example: None,
+                default: None,
             };
         };
 
@@ -125,6 +165,7 @@ pub fn implement(ident: syn::Ident, enum_variants: Vec<Variant>) -> TokenStream
     }
 
     let str_ident = ident.to_string();
+    let description = docs::description_tokens(&description);
 
     let gen = quote::quote! {
         impl rpc::open_schema::SchemableType for #ident {
@@ -138,6 +179,11 @@ pub fn implement(ident: syn::Ident, enum_variants: Vec<Variant>) -> TokenStream
                 String::from(#str_ident)
             }
 
+            #[inline]
+            fn type_description() -> Option<String> {
+                #description
+            }
+
             fn type_fields(
                 _: rpc::open_schema::schema::TypeMapRef,
             ) -> Vec<rpc::open_schema::schema::SchemaField> {
@@ -222,10 +268,30 @@ pub fn implement(ident: syn::Ident, enum_variants: Vec<Variant>) -> TokenStream
             }
         }
 
+        impl rpc::open_schema::SchemableError for #ident {
+            fn apply_schema(
+                proc: &mut rpc::open_schema::schema::SchemaProcedure,
+                type_map: rpc::open_schema::schema::TypeMapRef,
+            ) {
+                proc.error = Some(
+                    <#ident as rpc::open_schema::SchemableField>::get_rel_type(),
+                );
+
+                // Insert enum into type map
+                rpc::open_schema::schema::insert_into_type_map_ref::<#ident>(
+                    rpc::open_schema::schema::SchemaTypes::Enum,
+                    type_map.clone(),
+                );
+            }
+        }
+
         impl rpc::Responder for #ident {
             #[inline]
-            fn into_json(self, call_key: rpc::call::CallKey) -> rpc::json::JsonValue {
-                rpc::procedure::response::ProcedureResponse::result(call_key, self).into()
+            fn into_response(
+                self,
+                call_key: rpc::call::CallKey,
+            ) -> rpc::procedure::response::ProcedureResponse {
+                rpc::procedure::response::ProcedureResponse::result(call_key, self)
             }
         }
     };
@@ -1,73 +1,92 @@
 use proc_macro::TokenStream;
 use syn::{punctuated::Punctuated, token::Comma, Field};
 
-use convert_case::{Case, Casing};
-
-use crate::rename;
+use crate::{deprecation, docs, generics, rename, values};
 
 /// Struct that represents a field of a struct
 struct StructField {
-    /// Name of the field
-    ident: syn::Ident,
+    /// Serialized name of the field
+    name: String,
     /// Type of the field
     ty: syn::Type,
+    /// Doc comment of the field
+    description: Option<String>,
+    /// Deprecation of the field, with optional note
+    deprecation: Option<Option<String>>,
+    /// Example value of the field
+    example: Option<syn::Expr>,
+    /// Serde attributes of the field
+    attrs: rename::FieldAttrs,
 }
 
 pub fn implement(
     ident: syn::Ident,
+    generics: syn::Generics,
     struct_fields: Punctuated<Field, Comma>,
-    rename_all: rename::RenameType,
+    container: rename::ContainerAttrs,
+    description: Option<String>,
 ) -> TokenStream {
     // List of all properties of the struct
     let mut properties = Vec::new();
 
     // Iterate over all fields of the struct
     for field in struct_fields {
-        // let vis = &field.vis;
+        let attrs = rename::find_field_attrs(&field.attrs);
 
-        // if field is private, skip it
-        /* if let syn::Visibility::Inherited = vis {
+        // Skipped field is not part of the wire format
+        if attrs.skip {
             continue;
         }
 
-        // if field is restricted, skip it
-        if let syn::Visibility::Restricted(_) = vis {
-            continue;
-        } */
-
         let ident = field.ident.as_ref().unwrap();
 
+        let name = match &attrs.rename {
+            Some(rename) => rename.clone(),
+            None => container.rename_all.apply_to_field(&ident.to_string()),
+        };
+
         let field = StructField {
-            ident: ident.clone(),
+            name,
             ty: field.ty.clone(),
+            description: docs::find_doc_in_attrs(&field.attrs),
+            deprecation: deprecation::find_deprecation_in_attrs(&field.attrs),
+            example: values::find_example_in_attrs(&field.attrs),
+            attrs,
         };
 
         properties.push(field);
     }
 
     let mut fields = Vec::new();
+    let mut predicates = Vec::new();
 
     // Iterate over all properties of the struct
     for property in properties {
-        // Get name of the field
-        let ident = property.ident;
-        let mut str_ident = ident.to_string();
-
-        match rename_all {
-            rename::RenameType::CamelCase => {
-                str_ident = str_ident.to_case(Case::Camel);
-            }
-            _ => {}
-        };
+        let str_ident = property.name;
 
         // Get type of the field
         let ty = property.ty;
+        let field_description = docs::description_tokens(&property.description);
+        let field_deprecation = deprecation::deprecation_tokens(&property.deprecation);
+
+        // Field with default value can be missing
+        let optional = property.attrs.optional || container.default;
+        let flatten = property.attrs.flatten;
+        let aliases = rename::aliases_tokens(&property.attrs.aliases);
+        let field_values = values::values_tokens(&ty, &property.example, &property.attrs.default);
+        predicates.extend(values::default_predicates(&ty, &property.attrs.default));
 
         let mut field_code = quote::quote! {
             let mut field = rpc::open_schema::schema::SchemaField {
                 name: String::from(#str_ident),
                 rel: None,
                 value: None,
+                description: #field_description,
+                #field_deprecation
+                optional: #optional,
+                flatten: #flatten,
+                aliases: #aliases,
+                #field_values
             };
         };
 
@@ -85,10 +104,18 @@ pub fn implement(
         fields.push(field_code);
     }
 
-    let str_ident = ident.to_string();
+    let type_name = generics::type_name_tokens(&ident, &generics);
+    let description = docs::description_tokens(&description);
+
+    // Every instance of generic struct has its own type in schema
+    let schemable_generics = generics::with_predicates(generics::schemable_generics(&generics), predicates);
+    let (impl_generics, ty_generics, where_clause) = schemable_generics.split_for_impl();
+
+    let responder_generics = generics::responder_generics(&generics);
+    let (responder_impl_generics, _, responder_where_clause) = responder_generics.split_for_impl();
 
     let gen = quote::quote! {
-        impl rpc::open_schema::SchemableType for #ident {
+        impl #impl_generics rpc::open_schema::SchemableType for #ident #ty_generics #where_clause {
             #[inline]
             fn schema_type() -> rpc::open_schema::schema::SchemaTypes {
                 rpc::open_schema::schema::SchemaTypes::Struct
@@ -96,7 +123,12 @@ pub fn implement(
 
             #[inline]
             fn type_name() -> String {
-                String::from(#str_ident)
+                #type_name
+            }
+
+            #[inline]
+            fn type_description() -> Option<String> {
+                #description
             }
 
             fn type_fields(
@@ -110,25 +142,25 @@ pub fn implement(
             }
         }
 
-        impl rpc::open_schema::schema::FieldType for #ident {
+        impl #impl_generics rpc::open_schema::schema::FieldType for #ident #ty_generics #where_clause {
             #[inline]
             fn field_type() -> rpc::open_schema::schema::SchemaFieldType {
                 rpc::open_schema::schema::SchemaFieldType::Struct
             }
         }
 
-        impl rpc::open_schema::schema::FieldFormat for #ident {
+        impl #impl_generics rpc::open_schema::schema::FieldFormat for #ident #ty_generics #where_clause {
             #[inline]
             fn field_format() -> rpc::open_schema::schema::SchemaFieldFormat {
                 rpc::open_schema::schema::SchemaFieldFormat::Type
             }
         }
 
-        impl rpc::open_schema::SchemableField for #ident {
+        impl #impl_generics rpc::open_schema::SchemableField for #ident #ty_generics #where_clause {
             #[inline]
             fn get_rel_type() -> rpc::open_schema::schema::SchemaFieldRel {
                 rpc::open_schema::schema::SchemaFieldRel::Struct {
-                    name: String::from(#str_ident),
+                    name: <Self as rpc::open_schema::SchemableType>::type_name(),
                 }
             }
 
@@ -138,55 +170,75 @@ pub fn implement(
                 type_map: rpc::open_schema::schema::TypeMapRef,
             ) {
                 field.rel = Some(
-                    <#ident as rpc::open_schema::SchemableField>::get_rel_type()
+                    <Self as rpc::open_schema::SchemableField>::get_rel_type()
                 );
 
                 // Insert struct into type map
-                rpc::open_schema::schema::insert_into_type_map_ref::<#ident>(
+                rpc::open_schema::schema::insert_into_type_map_ref::<Self>(
                     rpc::open_schema::schema::SchemaTypes::Struct,
                     type_map.clone(),
                 );
             }
         }
 
-        impl rpc::open_schema::SchemableParams for #ident {
+        impl #impl_generics rpc::open_schema::SchemableParams for #ident #ty_generics #where_clause {
             fn apply_schema(
                 proc: &mut rpc::open_schema::schema::SchemaProcedure,
                 type_map: rpc::open_schema::schema::TypeMapRef,
             ) {
                 proc.params = Some(
-                    <#ident as rpc::open_schema::SchemableField>::get_rel_type(),
+                    <Self as rpc::open_schema::SchemableField>::get_rel_type(),
                 );
 
                 // Insert struct into type map
-                rpc::open_schema::schema::insert_into_type_map_ref::<#ident>(
+                rpc::open_schema::schema::insert_into_type_map_ref::<Self>(
                     rpc::open_schema::schema::SchemaTypes::Struct,
                     type_map.clone(),
                 );
             }
         }
 
-        impl rpc::open_schema::SchemableResult for #ident {
+        impl #impl_generics rpc::open_schema::SchemableResult for #ident #ty_generics #where_clause {
             fn apply_schema(
                 proc: &mut rpc::open_schema::schema::SchemaProcedure,
                 type_map: rpc::open_schema::schema::TypeMapRef,
             ) {
                 proc.result = Some(
-                    <#ident as rpc::open_schema::SchemableField>::get_rel_type(),
+                    <Self as rpc::open_schema::SchemableField>::get_rel_type(),
+                );
+
+                // Insert struct into type map
+                rpc::open_schema::schema::insert_into_type_map_ref::<Self>(
+                    rpc::open_schema::schema::SchemaTypes::Struct,
+                    type_map.clone(),
+                );
+            }
+        }
+
+        impl #impl_generics rpc::open_schema::SchemableError for #ident #ty_generics #where_clause {
+            fn apply_schema(
+                proc: &mut rpc::open_schema::schema::SchemaProcedure,
+                type_map: rpc::open_schema::schema::TypeMapRef,
+            ) {
+                proc.error = Some(
+                    <Self as rpc::open_schema::SchemableField>::get_rel_type(),
                 );
 
                 // Insert struct into type map
-                rpc::open_schema::schema::insert_into_type_map_ref::<#ident>(
+                rpc::open_schema::schema::insert_into_type_map_ref::<Self>(
                     rpc::open_schema::schema::SchemaTypes::Struct,
                     type_map.clone(),
                 );
             }
         }
 
-        impl rpc::Responder for #ident {
+        impl #responder_impl_generics rpc::Responder for #ident #ty_generics #responder_where_clause {
             #[inline]
-            fn into_json(self, call_key: rpc::call::CallKey) -> rpc::json::JsonValue {
-                rpc::procedure::response::ProcedureResponse::result(call_key, self).into()
+            fn into_response(
+                self,
+                call_key: rpc::call::CallKey,
+            ) -> rpc::procedure::response::ProcedureResponse {
+                rpc::procedure::response::ProcedureResponse::result(call_key, self)
             }
         }
     };
@@ -0,0 +1,316 @@
+use proc_macro::TokenStream;
+use syn::Variant;
+
+use crate::{deprecation, docs, generics, rename, values};
+
+/// Data-carrying enum, every variant is described by its payload:
+/// unit variant has no payload, newtype variant has payload of its type,
+/// tuple variant has fixed-length tuple and struct variant has its own struct `Enum_Variant`.
+pub fn implement(
+    ident: syn::Ident,
+    generics: syn::Generics,
+    enum_variants: Vec<Variant>,
+    container: rename::ContainerAttrs,
+    description: Option<String>,
+) -> TokenStream {
+    let mut fields = Vec::new();
+    let mut predicates = Vec::new();
+
+    for variant in enum_variants {
+        let attrs = match rename::find_variant_attrs(&variant.attrs) {
+            Ok(attrs) => attrs,
+            Err(e) => return e.to_compile_error().into(),
+        };
+
+        // Skipped variant is not part of the wire format
+        if attrs.skip {
+            continue;
+        }
+
+        let str_variant = variant.ident.to_string();
+        let str_name = match &attrs.rename {
+            Some(rename) => rename.clone(),
+            None => container.rename_all.apply_to_variant(&str_variant),
+        };
+
+        // Fields of struct variant are renamed by rule of variant or of all variants
+        let fields_rename_all = match attrs.rename_all {
+            rename::RenameRule::None => container.rename_all_fields,
+            rename_all => rename_all,
+        };
+
+        let aliases = rename::aliases_tokens(&attrs.aliases);
+        let field_description = docs::description_tokens(&docs::find_doc_in_attrs(&variant.attrs));
+        let field_deprecation =
+            deprecation::deprecation_tokens(&deprecation::find_deprecation_in_attrs(&variant.attrs));
+
+        let mut field_code = quote::quote! {
+            let mut field = rpc::open_schema::schema::SchemaField {
+                name: String::from(#str_variant),
+                rel: None,
+                value: Some(String::from(#str_name)),
+                description: #field_description,
+                #field_deprecation
+                optional: false,
+                flatten: false,
+                aliases: #aliases,
+                example: None,
+                default: None,
+            };
+        };
+
+        match variant.fields {
+            syn::Fields::Unit => {}
+            syn::Fields::Unnamed(syn::FieldsUnnamed { unnamed, .. }) if unnamed.len() == 1 => {
+                let ty = &unnamed[0].ty;
+
+                field_code = quote::quote! {
+                    #field_code
+
+                    <#ty as rpc::open_schema::SchemableField>::explore_type(&mut field, type_map.clone());
+                };
+            }
+            syn::Fields::Unnamed(syn::FieldsUnnamed { unnamed, .. }) => {
+                let types = unnamed.iter().map(|field| &field.ty).collect::<Vec<_>>();
+
+                field_code = quote::quote! {
+                    #field_code
+
+                    #(<#types as rpc::open_schema::SchemableField>::explore_type(&mut field, type_map.clone());)*
+                    field.rel = Some(rpc::open_schema::schema::SchemaFieldRel::Tuple {
+                        items: vec![
+                            #(<#types as rpc::open_schema::SchemableField>::get_rel_type(),)*
+                        ],
+                    });
+                };
+            }
+            syn::Fields::Named(syn::FieldsNamed { named, .. }) => {
+                let mut variant_fields = Vec::new();
+
+                for variant_field in named {
+                    let variant_field_attrs = rename::find_field_attrs(&variant_field.attrs);
+
+                    // Skipped field is not part of the wire format
+                    if variant_field_attrs.skip {
+                        continue;
+                    }
+
+                    let ty = variant_field.ty;
+                    let str_ident = match variant_field_attrs.rename {
+                        Some(rename) => rename,
+                        None => fields_rename_all.apply_to_field(&variant_field.ident.unwrap().to_string()),
+                    };
+                    let optional = variant_field_attrs.optional;
+                    let flatten = variant_field_attrs.flatten;
+                    let variant_field_aliases = rename::aliases_tokens(&variant_field_attrs.aliases);
+                    let variant_field_values = values::values_tokens(
+                        &ty,
+                        &values::find_example_in_attrs(&variant_field.attrs),
+                        &variant_field_attrs.default,
+                    );
+                    predicates.extend(values::default_predicates(&ty, &variant_field_attrs.default));
+                    let variant_field_description =
+                        docs::description_tokens(&docs::find_doc_in_attrs(&variant_field.attrs));
+                    let variant_field_deprecation = deprecation::deprecation_tokens(
+                        &deprecation::find_deprecation_in_attrs(&variant_field.attrs),
+                    );
+
+                    variant_fields.push(quote::quote! {
+                        let mut variant_field = rpc::open_schema::schema::SchemaField {
+                            name: String::from(#str_ident),
+                            rel: None,
+                            value: None,
+                            description: #variant_field_description,
+                            #variant_field_deprecation
+                            optional: #optional,
+                            flatten: #flatten,
+                            aliases: #variant_field_aliases,
+                            #variant_field_values
+                        };
+
+                        <#ty as rpc::open_schema::SchemableField>::explore_type(
+                            &mut variant_field,
+                            type_map.clone(),
+                        );
+                        variant_fields.push(variant_field);
+                    });
+                }
+
+                field_code = quote::quote! {
+                    #field_code
+
+                    // Struct variant is described by its own struct
+                    let name = format!(
+                        "{}_{}",
+                        <Self as rpc::open_schema::SchemableType>::type_name(),
+                        #str_variant,
+                    );
+
+                    let mut variant_fields = Vec::new();
+                    #(#variant_fields)*
+
+                    rpc::open_schema::schema::insert_fields_into_type_map_ref(
+                        name.clone(),
+                        rpc::open_schema::schema::SchemaTypes::Struct,
+                        variant_fields,
+                        None,
+                        type_map.clone(),
+                    );
+
+                    field.rel = Some(rpc::open_schema::schema::SchemaFieldRel::Struct { name });
+                };
+            }
+        }
+
+        fields.push(quote::quote! {
+            #field_code
+            fields.push(field);
+        });
+    }
+
+    let type_name = generics::type_name_tokens(&ident, &generics);
+    let description = docs::description_tokens(&description);
+    let tagging = container.tagging.to_tokens();
+
+    let schemable_generics = generics::with_predicates(generics::schemable_generics(&generics), predicates);
+    let (impl_generics, ty_generics, where_clause) = schemable_generics.split_for_impl();
+
+    let responder_generics = generics::responder_generics(&generics);
+    let (responder_impl_generics, _, responder_where_clause) = responder_generics.split_for_impl();
+
+    let gen = quote::quote! {
+        impl #impl_generics rpc::open_schema::SchemableType for #ident #ty_generics #where_clause {
+            #[inline]
+            fn schema_type() -> rpc::open_schema::schema::SchemaTypes {
+                rpc::open_schema::schema::SchemaTypes::Union
+            }
+
+            #[inline]
+            fn type_name() -> String {
+                #type_name
+            }
+
+            #[inline]
+            fn type_description() -> Option<String> {
+                #description
+            }
+
+            #[inline]
+            fn type_tagging() -> Option<rpc::open_schema::schema::SchemaTagging> {
+                Some(#tagging)
+            }
+
+            fn type_fields(
+                type_map: rpc::open_schema::schema::TypeMapRef,
+            ) -> Vec<rpc::open_schema::schema::SchemaField> {
+                let mut fields = Vec::new();
+
+                #({ #fields })*
+
+                fields
+            }
+        }
+
+        impl #impl_generics rpc::open_schema::schema::FieldType for #ident #ty_generics #where_clause {
+            #[inline]
+            fn field_type() -> rpc::open_schema::schema::SchemaFieldType {
+                rpc::open_schema::schema::SchemaFieldType::Object
+            }
+        }
+
+        impl #impl_generics rpc::open_schema::schema::FieldFormat for #ident #ty_generics #where_clause {
+            #[inline]
+            fn field_format() -> rpc::open_schema::schema::SchemaFieldFormat {
+                rpc::open_schema::schema::SchemaFieldFormat::Type
+            }
+        }
+
+        impl #impl_generics rpc::open_schema::SchemableField for #ident #ty_generics #where_clause {
+            #[inline]
+            fn get_rel_type() -> rpc::open_schema::schema::SchemaFieldRel {
+                rpc::open_schema::schema::SchemaFieldRel::Union {
+                    name: <Self as rpc::open_schema::SchemableType>::type_name(),
+                }
+            }
+
+            #[inline]
+            fn explore_type(
+                field: &mut rpc::open_schema::schema::SchemaField,
+                type_map: rpc::open_schema::schema::TypeMapRef,
+            ) {
+                field.rel = Some(
+                    <Self as rpc::open_schema::SchemableField>::get_rel_type()
+                );
+
+                // Insert union into type map
+                rpc::open_schema::schema::insert_into_type_map_ref::<Self>(
+                    rpc::open_schema::schema::SchemaTypes::Union,
+                    type_map.clone(),
+                );
+            }
+        }
+
+        impl #impl_generics rpc::open_schema::SchemableParams for #ident #ty_generics #where_clause {
+            fn apply_schema(
+                proc: &mut rpc::open_schema::schema::SchemaProcedure,
+                type_map: rpc::open_schema::schema::TypeMapRef,
+            ) {
+                proc.params = Some(
+                    <Self as rpc::open_schema::SchemableField>::get_rel_type(),
+                );
+
+                // Insert union into type map
+                rpc::open_schema::schema::insert_into_type_map_ref::<Self>(
+                    rpc::open_schema::schema::SchemaTypes::Union,
+                    type_map.clone(),
+                );
+            }
+        }
+
+        impl #impl_generics rpc::open_schema::SchemableResult for #ident #ty_generics #where_clause {
+            fn apply_schema(
+                proc: &mut rpc::open_schema::schema::SchemaProcedure,
+                type_map: rpc::open_schema::schema::TypeMapRef,
+            ) {
+                proc.result = Some(
+                    <Self as rpc::open_schema::SchemableField>::get_rel_type(),
+                );
+
+                // Insert union into type map
+                rpc::open_schema::schema::insert_into_type_map_ref::<Self>(
+                    rpc::open_schema::schema::SchemaTypes::Union,
+                    type_map.clone(),
+                );
+            }
+        }
+
+        impl #impl_generics rpc::open_schema::SchemableError for #ident #ty_generics #where_clause {
+            fn apply_schema(
+                proc: &mut rpc::open_schema::schema::SchemaProcedure,
+                type_map: rpc::open_schema::schema::TypeMapRef,
+            ) {
+                proc.error = Some(
+                    <Self as rpc::open_schema::SchemableField>::get_rel_type(),
+                );
+
+                // Insert union into type map
+                rpc::open_schema::schema::insert_into_type_map_ref::<Self>(
+                    rpc::open_schema::schema::SchemaTypes::Union,
+                    type_map.clone(),
+                );
+            }
+        }
+
+        impl #responder_impl_generics rpc::Responder for #ident #ty_generics #responder_where_clause {
+            #[inline]
+            fn into_response(
+                self,
+                call_key: rpc::call::CallKey,
+            ) -> rpc::procedure::response::ProcedureResponse {
+                rpc::procedure::response::ProcedureResponse::result(call_key, self)
+            }
+        }
+    };
+
+    gen.into()
+}
@@ -1,97 +1,125 @@
 use proc_macro::TokenStream;
 use syn::{punctuated::Punctuated, token::Comma, Field};
 
-/// Struct that represents a field of a struct
-struct StructField {
-    /// Type of the field
-    ty: syn::Type,
-}
-
-pub fn implement(ident: syn::Ident, struct_fields: Punctuated<Field, Comma>) -> TokenStream {
-    // List of all properties of the struct
-    let mut properties = Vec::new();
-
-    // Iterate over all fields of the struct
-    for field in struct_fields {
-        let ident = field.ident.as_ref().unwrap();
-        let field = StructField { ty: field.ty.clone() };
-
-        properties.push(field);
-    }
-
-    let mut fields = Vec::new();
-
-    // Iterate over all properties of the struct
-    for property in properties {
-        // Get type of the field
-        let ty = property.ty;
-
-        let mut field_code = quote::quote! {
-            let mut field = rpc::open_schema::schema::SchemaField {
-                name: String::from(#str_ident),
-                rel: None,
-                value: None,
+use crate::{docs, generics, rename};
+
+/// Newtype `UserId(Uuid)` is alias of its inner type,
+/// tuple struct `Point(i32, i32)` is alias of fixed-length tuple.
+/// Struct with `#[serde(transparent)]` is alias of its only serialized field.
+pub fn implement(
+    ident: syn::Ident,
+    generics: syn::Generics,
+    struct_fields: Punctuated<Field, Comma>,
+    transparent: bool,
+    description: Option<String>,
+) -> TokenStream {
+    // Serde decides between newtype and tuple by count of all fields, skipped fields included
+    let is_newtype = struct_fields.len() == 1;
+
+    // Types of all serialized fields of the struct
+    let types = struct_fields
+        .into_iter()
+        .filter(|field| !rename::find_field_attrs(&field.attrs).skip)
+        .map(|field| field.ty)
+        .collect::<Vec<_>>();
+
+    // Aliased type is only in type map, so recursive alias like `Tree(Vec<Tree>)` ends at its name
+    let explore_code = match types.as_slice() {
+        [] => {
+            return syn::Error::new_spanned(&ident, "Struct without serialized fields is not supported")
+                .to_compile_error()
+                .into();
+        }
+        // Tuple struct with skipped fields is serialized as array, even with one serialized field
+        [ty] if is_newtype || transparent => quote::quote! {
+            <#ty as rpc::open_schema::SchemableField>::explore_type(&mut field, type_map.clone());
+        },
+        types => {
+            let aliased_rel = quote::quote! {
+                rpc::open_schema::schema::SchemaFieldRel::Tuple {
+                    items: vec![
+                        #(<#types as rpc::open_schema::SchemableField>::get_rel_type(),)*
+                    ],
+                }
             };
-        };
 
-        field_code = quote::quote! {
-            #field_code
-
-            <#ty as rpc::open_schema::SchemableField>::explore_type(&mut field, type_map.clone());
-        };
+            // Insert types of all items into type map
+            quote::quote! {
+                #(<#types as rpc::open_schema::SchemableField>::explore_type(&mut field, type_map.clone());)*
+                field.rel = Some(#aliased_rel);
+            }
+        }
+    };
 
-        field_code = quote::quote! {
-            #field_code
-            fields.push(field);
-        };
+    let type_name = generics::type_name_tokens(&ident, &generics);
+    let description = docs::description_tokens(&description);
 
-        fields.push(field_code);
-    }
+    let schemable_generics = generics::schemable_generics(&generics);
+    let (impl_generics, ty_generics, where_clause) = schemable_generics.split_for_impl();
 
-    let str_ident = ident.to_string();
+    let responder_generics = generics::responder_generics(&generics);
+    let (responder_impl_generics, _, responder_where_clause) = responder_generics.split_for_impl();
 
     let gen = quote::quote! {
-        impl rpc::open_schema::SchemableType for #ident {
+        impl #impl_generics rpc::open_schema::SchemableType for #ident #ty_generics #where_clause {
             #[inline]
             fn schema_type() -> rpc::open_schema::schema::SchemaTypes {
-                rpc::open_schema::schema::SchemaTypes::Struct
+                rpc::open_schema::schema::SchemaTypes::Alias
             }
 
             #[inline]
             fn type_name() -> String {
-                String::from(#str_ident)
+                #type_name
+            }
+
+            #[inline]
+            fn type_description() -> Option<String> {
+                #description
             }
 
             fn type_fields(
                 type_map: rpc::open_schema::schema::TypeMapRef,
             ) -> Vec<rpc::open_schema::schema::SchemaField> {
-                let mut fields = Vec::new();
-
-                #(#fields)*
-
-                fields
+                // Alias has one unnamed field with aliased type
+                let mut field = rpc::open_schema::schema::SchemaField {
+                    name: String::new(),
+                    rel: None,
+                    value: None,
+                    description: None,
+                    deprecated: false,
+                    deprecation_note: None,
+                    optional: false,
+                    flatten: false,
+                    aliases: Vec::new(),
+                    example: None,
+                    default: None,
+                };
+
+                #explore_code
+
+                vec![field]
             }
         }
 
-        impl rpc::open_schema::schema::FieldType for #ident {
+        impl #impl_generics rpc::open_schema::schema::FieldType for #ident #ty_generics #where_clause {
             #[inline]
             fn field_type() -> rpc::open_schema::schema::SchemaFieldType {
-                rpc::open_schema::schema::SchemaFieldType::Struct
+                rpc::open_schema::schema::SchemaFieldType::Object
             }
         }
 
-        impl rpc::open_schema::schema::FieldFormat for #ident {
+        impl #impl_generics rpc::open_schema::schema::FieldFormat for #ident #ty_generics #where_clause {
             #[inline]
             fn field_format() -> rpc::open_schema::schema::SchemaFieldFormat {
                 rpc::open_schema::schema::SchemaFieldFormat::Type
             }
         }
 
-        impl rpc::open_schema::SchemableField for #ident {
+        impl #impl_generics rpc::open_schema::SchemableField for #ident #ty_generics #where_clause {
             #[inline]
             fn get_rel_type() -> rpc::open_schema::schema::SchemaFieldRel {
-                rpc::open_schema::schema::SchemaFieldRel::Struct {
-                    name: String::from(#str_ident),
+                rpc::open_schema::schema::SchemaFieldRel::Alias {
+                    name: <Self as rpc::open_schema::SchemableType>::type_name(),
                 }
             }
 
@@ -101,55 +129,75 @@ pub fn implement(ident: syn::Ident, struct_fields: Punctuated<Field, Comma>) ->
                 type_map: rpc::open_schema::schema::TypeMapRef,
             ) {
                 field.rel = Some(
-                    <#ident as rpc::open_schema::SchemableField>::get_rel_type()
+                    <Self as rpc::open_schema::SchemableField>::get_rel_type()
                 );
 
-                // Insert struct into type map
-                rpc::open_schema::schema::insert_into_type_map_ref::<#ident>(
-                    rpc::open_schema::schema::SchemaTypes::Struct,
+                // Insert alias into type map
+                rpc::open_schema::schema::insert_into_type_map_ref::<Self>(
+                    rpc::open_schema::schema::SchemaTypes::Alias,
                     type_map.clone(),
                 );
             }
         }
 
-        impl rpc::open_schema::SchemableParams for #ident {
+        impl #impl_generics rpc::open_schema::SchemableParams for #ident #ty_generics #where_clause {
             fn apply_schema(
                 proc: &mut rpc::open_schema::schema::SchemaProcedure,
                 type_map: rpc::open_schema::schema::TypeMapRef,
             ) {
                 proc.params = Some(
-                    <#ident as rpc::open_schema::SchemableField>::get_rel_type(),
+                    <Self as rpc::open_schema::SchemableField>::get_rel_type(),
                 );
 
-                // Insert struct into type map
-                rpc::open_schema::schema::insert_into_type_map_ref::<#ident>(
-                    rpc::open_schema::schema::SchemaTypes::Struct,
+                // Insert alias into type map
+                rpc::open_schema::schema::insert_into_type_map_ref::<Self>(
+                    rpc::open_schema::schema::SchemaTypes::Alias,
                     type_map.clone(),
                 );
             }
         }
 
-        impl rpc::open_schema::SchemableResult for #ident {
+        impl #impl_generics rpc::open_schema::SchemableResult for #ident #ty_generics #where_clause {
             fn apply_schema(
                 proc: &mut rpc::open_schema::schema::SchemaProcedure,
                 type_map: rpc::open_schema::schema::TypeMapRef,
             ) {
                 proc.result = Some(
-                    <#ident as rpc::open_schema::SchemableField>::get_rel_type(),
+                    <Self as rpc::open_schema::SchemableField>::get_rel_type(),
+                );
+
+                // Insert alias into type map
+                rpc::open_schema::schema::insert_into_type_map_ref::<Self>(
+                    rpc::open_schema::schema::SchemaTypes::Alias,
+                    type_map.clone(),
+                );
+            }
+        }
+
+        impl #impl_generics rpc::open_schema::SchemableError for #ident #ty_generics #where_clause {
+            fn apply_schema(
+                proc: &mut rpc::open_schema::schema::SchemaProcedure,
+                type_map: rpc::open_schema::schema::TypeMapRef,
+            ) {
+                proc.error = Some(
+                    <Self as rpc::open_schema::SchemableField>::get_rel_type(),
                 );
 
-                // Insert struct into type map
-                rpc::open_schema::schema::insert_into_type_map_ref::<#ident>(
-                    rpc::open_schema::schema::SchemaTypes::Struct,
+                // Insert alias into type map
+                rpc::open_schema::schema::insert_into_type_map_ref::<Self>(
+                    rpc::open_schema::schema::SchemaTypes::Alias,
                     type_map.clone(),
                 );
             }
         }
 
-        impl rpc::Responder for #ident {
+        impl #responder_impl_generics rpc::Responder for #ident #ty_generics #responder_where_clause {
             #[inline]
-            fn into_json(self, call_key: rpc::call::CallKey) -> rpc::json::JsonValue {
-                rpc::procedure::response::ProcedureResponse::result(call_key, self).into()
+            fn into_response(
+                self,
+                call_key: rpc::call::CallKey,
+            ) -> rpc::procedure::response::ProcedureResponse {
+                rpc::procedure::response::ProcedureResponse::result(call_key, self)
             }
         }
     };
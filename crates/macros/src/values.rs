@@ -0,0 +1,63 @@
+use crate::rename::FieldDefault;
+
+/// Example value of the field given by `#[rpc(example = ...)]`
+pub fn find_example_in_attrs(attrs: &[syn::Attribute]) -> Option<syn::Expr> {
+    let mut example = None;
+
+    for attr in attrs {
+        if !attr.path().is_ident("rpc") {
+            continue;
+        }
+
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("example") {
+                example = Some(meta.value()?.parse::<syn::Expr>()?);
+
+                Ok(())
+            } else {
+                Err(meta.error("Unsupported rpc attribute, expected `example = ...`"))
+            }
+        })
+        .unwrap();
+    }
+
+    example
+}
+
+/// Tokens of `example` and `default` fields of `SchemaField`
+pub fn values_tokens(
+    ty: &syn::Type,
+    example: &Option<syn::Expr>,
+    default: &Option<FieldDefault>,
+) -> proc_macro2::TokenStream {
+    let example = match example {
+        Some(example) => quote::quote! {
+            rpc::open_schema::schema::field_value(&(#example))
+        },
+        None => quote::quote! { None },
+    };
+
+    let default = match default {
+        Some(FieldDefault::Trait) => quote::quote! {
+            rpc::open_schema::schema::field_value(&<#ty as Default>::default())
+        },
+        Some(FieldDefault::Path(path)) => quote::quote! {
+            rpc::open_schema::schema::field_value::<#ty>(&#path())
+        },
+        None => quote::quote! { None },
+    };
+
+    quote::quote! {
+        example: #example,
+        default: #default,
+    }
+}
+
+/// Bounds required to serialize default value of the field of generic type
+pub fn default_predicates(ty: &syn::Type, default: &Option<FieldDefault>) -> Vec<syn::WherePredicate> {
+    match default {
+        Some(FieldDefault::Trait) => vec![syn::parse_quote!(#ty: rpc::serde::Serialize + Default)],
+        Some(FieldDefault::Path(_)) => vec![syn::parse_quote!(#ty: rpc::serde::Serialize)],
+        None => Vec::new(),
+    }
+}
@@ -1,5 +1,32 @@
+use serde::{Deserialize, Serialize};
+
 pub trait AppInfoLike: Sized {
     fn name(&self) -> String;
     fn version(&self) -> String;
     fn description(&self) -> String;
 }
+
+/// App info of the schema loaded from document
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct SchemaInfo {
+    pub name: String,
+    pub version: String,
+    pub description: String,
+}
+
+impl AppInfoLike for SchemaInfo {
+    #[inline]
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    #[inline]
+    fn version(&self) -> String {
+        self.version.clone()
+    }
+
+    #[inline]
+    fn description(&self) -> String {
+        self.description.clone()
+    }
+}
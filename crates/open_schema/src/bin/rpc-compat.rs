@@ -0,0 +1,47 @@
+//! Check compatibility of two schemas
+//!
+//! Usage: `rpc-compat <old-schema.json> <new-schema.json>`
+//!
+//! Exits with code 1 when a breaking change is found, 2 when schemas can not be loaded.
+
+use std::{fs, process::ExitCode};
+
+use rpc_openschema::{applike::SchemaInfo, compat, schema::SchemaRoot};
+
+fn load(path: &str) -> Result<SchemaRoot<SchemaInfo>, String> {
+    let source = fs::read_to_string(path).map_err(|e| format!("{path}: {e}"))?;
+
+    serde_json::from_str(&source).map_err(|e| format!("{path}: {e}"))
+}
+
+fn main() -> ExitCode {
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+
+    let (old, new) = match args.as_slice() {
+        [old, new] => (old, new),
+        _ => {
+            eprintln!("Usage: rpc-compat <old-schema.json> <new-schema.json>");
+            return ExitCode::from(2);
+        }
+    };
+
+    let (old, new) = match (load(old), load(new)) {
+        (Ok(old), Ok(new)) => (old, new),
+        (Err(e), _) | (_, Err(e)) => {
+            eprintln!("{e}");
+            return ExitCode::from(2);
+        }
+    };
+
+    let report = compat::check(&old, &new);
+    print!("{report}");
+
+    if report.is_breaking() {
+        let count = report.breaking_changes().count();
+        eprintln!("{count} breaking change(s) found");
+
+        return ExitCode::from(1);
+    }
+
+    ExitCode::SUCCESS
+}
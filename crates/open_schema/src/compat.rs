@@ -0,0 +1,379 @@
+//! Compatibility checker of two schemas
+//!
+//! Compares procedures, types and errors of old and new schema and classifies
+//! every change as breaking or non-breaking for generated clients.
+
+use std::{collections::BTreeMap, fmt::Display};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    applike::AppInfoLike,
+    schema::{SchemaError, SchemaField, SchemaFieldRel, SchemaRoot, SchemaType, SchemaTypes},
+    SchemaProcedure, SCHEMA_PATH_SEPARATOR,
+};
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ChangeKind {
+    Added,
+    Removed,
+    Changed,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Change {
+    pub kind: ChangeKind,
+    pub breaking: bool,
+    /// Changed procedure or type, eg. `procedure users/get_user` or `type User.name`
+    pub subject: String,
+    pub message: String,
+}
+
+impl Change {
+    fn new(kind: ChangeKind, breaking: bool, subject: &str, message: &str) -> Self {
+        Self {
+            kind,
+            breaking,
+            subject: subject.to_string(),
+            message: message.to_string(),
+        }
+    }
+}
+
+impl Display for Change {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let breaking = if self.breaking { "BREAKING" } else { "ok" };
+
+        write!(f, "[{breaking}] {}: {}", self.subject, self.message)
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct CompatReport {
+    pub changes: Vec<Change>,
+}
+
+impl CompatReport {
+    /// Check if some change is breaking
+    pub fn is_breaking(&self) -> bool {
+        self.changes.iter().any(|change| change.breaking)
+    }
+
+    pub fn breaking_changes(&self) -> impl Iterator<Item = &Change> {
+        self.changes.iter().filter(|change| change.breaking)
+    }
+
+    fn push(&mut self, kind: ChangeKind, breaking: bool, subject: &str, message: &str) {
+        self.changes.push(Change::new(kind, breaking, subject, message));
+    }
+}
+
+impl Display for CompatReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for change in &self.changes {
+            writeln!(f, "{change}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Direction of data described by relation
+#[derive(Clone, Copy)]
+enum Direction {
+    /// Data sent by client, eg. params
+    Input,
+    /// Data received by client, eg. result
+    Output,
+}
+
+/// Compare old and new schema
+pub fn check<I, J>(old: &SchemaRoot<I>, new: &SchemaRoot<J>) -> CompatReport
+where
+    I: AppInfoLike + Serialize,
+    J: AppInfoLike + Serialize,
+{
+    let mut report = CompatReport::default();
+
+    check_procedures(&mut report, &old.procedures, &new.procedures);
+    check_types(&mut report, &old.types, &new.types);
+    check_errors(&mut report, "error", old.errors.values().collect(), new.errors.values().collect());
+
+    report
+}
+
+fn procedure_key(procedure: &SchemaProcedure) -> String {
+    if procedure.path.is_empty() {
+        return procedure.name.clone();
+    }
+
+    format!("{}{SCHEMA_PATH_SEPARATOR}{}", procedure.path, procedure.name)
+}
+
+fn check_procedures(report: &mut CompatReport, old: &[SchemaProcedure], new: &[SchemaProcedure]) {
+    let old = old.iter().map(|p| (procedure_key(p), p)).collect::<BTreeMap<_, _>>();
+    let new = new.iter().map(|p| (procedure_key(p), p)).collect::<BTreeMap<_, _>>();
+
+    for (key, old_procedure) in &old {
+        let subject = format!("procedure {key}");
+
+        let new_procedure = match new.get(key) {
+            Some(procedure) => procedure,
+            None => {
+                report.push(ChangeKind::Removed, true, &subject, "procedure was removed");
+                continue;
+            }
+        };
+
+        if old_procedure.id != new_procedure.id {
+            let message = format!("id changed from {} to {}", old_procedure.id, new_procedure.id);
+            report.push(ChangeKind::Changed, true, &subject, &message);
+        }
+
+        if old_procedure.ty != new_procedure.ty {
+            report.push(ChangeKind::Changed, true, &subject, "procedure type changed");
+        }
+
+        check_rel(
+            report,
+            &subject,
+            "params",
+            &old_procedure.params,
+            &new_procedure.params,
+            Direction::Input,
+        );
+        check_rel(
+            report,
+            &subject,
+            "result",
+            &old_procedure.result,
+            &new_procedure.result,
+            Direction::Output,
+        );
+        check_rel(
+            report,
+            &subject,
+            "error",
+            &old_procedure.error,
+            &new_procedure.error,
+            Direction::Output,
+        );
+        check_errors(
+            report,
+            &format!("{subject} error"),
+            old_procedure.errors.iter().collect(),
+            new_procedure.errors.iter().collect(),
+        );
+    }
+
+    for key in new.keys() {
+        if !old.contains_key(key) {
+            report.push(ChangeKind::Added, false, &format!("procedure {key}"), "procedure was added");
+        }
+    }
+}
+
+fn check_rel(
+    report: &mut CompatReport,
+    subject: &str,
+    name: &str,
+    old: &Option<SchemaFieldRel>,
+    new: &Option<SchemaFieldRel>,
+    direction: Direction,
+) {
+    if old == new {
+        return;
+    }
+
+    let (kind, breaking, message) = match (old, new, direction) {
+        (None, Some(SchemaFieldRel::Nullable { .. }), Direction::Input) => {
+            (ChangeKind::Added, false, format!("optional {name} were added"))
+        }
+        (None, Some(_), Direction::Input) => (ChangeKind::Added, true, format!("required {name} were added")),
+        (None, Some(_), Direction::Output) => (ChangeKind::Added, false, format!("{name} was added")),
+        (Some(_), None, Direction::Input) => (ChangeKind::Removed, false, format!("{name} were removed")),
+        (Some(_), None, Direction::Output) => (ChangeKind::Removed, true, format!("{name} was removed")),
+        (Some(old), Some(SchemaFieldRel::Nullable { value }), Direction::Input) if old == value.as_ref() => {
+            (ChangeKind::Changed, false, format!("{name} became nullable"))
+        }
+        (Some(SchemaFieldRel::Nullable { value }), Some(new), Direction::Output) if new == value.as_ref() => {
+            (ChangeKind::Changed, false, format!("{name} became non-nullable"))
+        }
+        _ => (ChangeKind::Changed, true, format!("{name} type changed")),
+    };
+
+    report.push(kind, breaking, subject, &message);
+}
+
+fn check_types(report: &mut CompatReport, old: &BTreeMap<String, SchemaType>, new: &BTreeMap<String, SchemaType>) {
+    for (name, old_type) in old {
+        let subject = format!("type {name}");
+
+        let new_type = match new.get(name) {
+            Some(ty) => ty,
+            None => {
+                report.push(ChangeKind::Removed, true, &subject, "type was removed");
+                continue;
+            }
+        };
+
+        if old_type.ty != new_type.ty {
+            report.push(ChangeKind::Changed, true, &subject, "kind of type changed");
+            continue;
+        }
+
+        if old_type.tagging != new_type.tagging {
+            report.push(ChangeKind::Changed, true, &subject, "representation of variants changed");
+        }
+
+        match old_type.ty {
+            SchemaTypes::Enum | SchemaTypes::Error | SchemaTypes::Union => {
+                check_values(report, name, &old_type.fields, &new_type.fields)
+            }
+            SchemaTypes::Struct => check_fields(report, name, &old_type.fields, &new_type.fields),
+            SchemaTypes::Alias => check_alias(report, name, &old_type.fields, &new_type.fields),
+        }
+    }
+
+    for name in new.keys() {
+        if !old.contains_key(name) {
+            report.push(ChangeKind::Added, false, &format!("type {name}"), "type was added");
+        }
+    }
+}
+
+/// Compare fields of struct
+///
+/// Types can be used as params and result, so only changes that are safe
+/// in both directions are non-breaking.
+fn check_fields(report: &mut CompatReport, type_name: &str, old: &[SchemaField], new: &[SchemaField]) {
+    for old_field in old {
+        let subject = format!("type {type_name}.{}", old_field.name);
+
+        let new_field = match new.iter().find(|field| field.name == old_field.name) {
+            Some(field) => field,
+            None => {
+                report.push(ChangeKind::Removed, true, &subject, "field was removed");
+                continue;
+            }
+        };
+
+        if old_field.optional != new_field.optional {
+            let message = match new_field.optional {
+                true => "field became optional",
+                false => "field became required",
+            };
+
+            report.push(ChangeKind::Changed, true, &subject, message);
+        }
+
+        if old_field.flatten != new_field.flatten {
+            report.push(ChangeKind::Changed, true, &subject, "flattening of field changed");
+        }
+
+        if old_field.rel == new_field.rel {
+            continue;
+        }
+
+        let message = match (&old_field.rel, &new_field.rel) {
+            (Some(SchemaFieldRel::Nullable { value }), Some(new)) if value.as_ref() == new => {
+                "field became non-nullable"
+            }
+            (Some(old), Some(SchemaFieldRel::Nullable { value })) if value.as_ref() == old => "field became nullable",
+            _ => "field type changed",
+        };
+
+        report.push(ChangeKind::Changed, true, &subject, message);
+    }
+
+    for new_field in new {
+        if old.iter().any(|field| field.name == new_field.name) {
+            continue;
+        }
+
+        let subject = format!("type {type_name}.{}", new_field.name);
+
+        match new_field.rel {
+            Some(SchemaFieldRel::Nullable { .. }) => {
+                report.push(ChangeKind::Added, false, &subject, "nullable field was added")
+            }
+            _ if new_field.optional => report.push(ChangeKind::Added, false, &subject, "optional field was added"),
+            _ => report.push(ChangeKind::Added, true, &subject, "required field was added"),
+        }
+    }
+}
+
+/// Compare aliased type of newtype or tuple struct
+fn check_alias(report: &mut CompatReport, type_name: &str, old: &[SchemaField], new: &[SchemaField]) {
+    let old = old.first().and_then(|field| field.rel.as_ref());
+    let new = new.first().and_then(|field| field.rel.as_ref());
+
+    if old != new {
+        report.push(ChangeKind::Changed, true, &format!("type {type_name}"), "aliased type changed");
+    }
+}
+
+/// Compare values of enum, error or union
+fn check_values(report: &mut CompatReport, type_name: &str, old: &[SchemaField], new: &[SchemaField]) {
+    for old_field in old {
+        let subject = format!("type {type_name}.{}", old_field.name);
+
+        let new_field = match new.iter().find(|field| field.name == old_field.name) {
+            Some(field) => field,
+            None => {
+                report.push(ChangeKind::Removed, true, &subject, "value was removed");
+                continue;
+            }
+        };
+
+        if old_field.value != new_field.value {
+            report.push(ChangeKind::Changed, true, &subject, "value changed");
+        }
+
+        if old_field.rel != new_field.rel {
+            report.push(ChangeKind::Changed, true, &subject, "value type changed");
+        }
+    }
+
+    for new_field in new {
+        if !old.iter().any(|field| field.name == new_field.name) {
+            let subject = format!("type {type_name}.{}", new_field.name);
+            report.push(ChangeKind::Added, false, &subject, "value was added");
+        }
+    }
+}
+
+/// Compare typed errors by error code
+///
+/// Clients handle errors by code, so removed code or changed HTTP code or payload is breaking.
+fn check_errors(report: &mut CompatReport, subject: &str, old: Vec<&SchemaError>, new: Vec<&SchemaError>) {
+    for old_error in &old {
+        let subject = format!("{subject} {}", old_error.code);
+
+        let new_error = match new.iter().find(|error| error.code == old_error.code) {
+            Some(error) => error,
+            None => {
+                report.push(ChangeKind::Removed, true, &subject, "error code was removed");
+                continue;
+            }
+        };
+
+        if old_error.http_code != new_error.http_code {
+            let message = format!("HTTP code changed from {} to {}", old_error.http_code, new_error.http_code);
+            report.push(ChangeKind::Changed, true, &subject, &message);
+        }
+
+        if old_error.payload != new_error.payload {
+            report.push(ChangeKind::Changed, true, &subject, "error payload type changed");
+        }
+    }
+
+    for new_error in &new {
+        if !old.iter().any(|error| error.code == new_error.code) {
+            let subject = format!("{subject} {}", new_error.code);
+            report.push(ChangeKind::Added, false, &subject, "error code was added");
+        }
+    }
+}
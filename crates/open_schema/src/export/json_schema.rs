@@ -0,0 +1,395 @@
+use serde_json::{json, Map, Value};
+
+use crate::schema::{
+    SchemaField, SchemaFieldFormat, SchemaFieldRel, SchemaFieldType, SchemaTagging, SchemaType, SchemaTypes, TypeMap,
+};
+
+/// Dialect of the exported JSON Schema
+pub const JSON_SCHEMA_DIALECT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+/// Target document of exported schemas, it decides references to types and formats of native types
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Dialect {
+    /// JSON Schema bundle, types are in `$defs`
+    JsonSchema,
+    /// OpenAPI document, types are component schemas
+    OpenApi,
+}
+
+impl Dialect {
+    /// Prefix of references to types
+    fn refs(self) -> &'static str {
+        match self {
+            Self::JsonSchema => "#/$defs/",
+            Self::OpenApi => "#/components/schemas/",
+        }
+    }
+}
+
+/// Convert types into JSON Schema (draft 2020-12) bundle with types in `$defs`
+pub fn to_json_schema(types: &TypeMap) -> Value {
+    let defs = types
+        .iter()
+        .map(|(name, ty)| (name.clone(), type_schema(ty, Dialect::JsonSchema)))
+        .collect::<Map<_, _>>();
+
+    json!({
+        "$schema": JSON_SCHEMA_DIALECT,
+        "$defs": defs,
+    })
+}
+
+/// Convert field relation into JSON Schema of the dialect
+pub(crate) fn rel_schema(rel: &SchemaFieldRel, dialect: Dialect) -> Value {
+    match rel {
+        SchemaFieldRel::Native { ty, format } => native_schema(ty, format, dialect),
+        SchemaFieldRel::Array { value } => json!({
+            "type": "array",
+            "items": rel_schema(value, dialect),
+        }),
+        SchemaFieldRel::Nullable { value } => json!({
+            "anyOf": [rel_schema(value, dialect), { "type": "null" }],
+        }),
+        // Aliases are defined in type map, like structs
+        SchemaFieldRel::Type { name, .. }
+        | SchemaFieldRel::Alias { name }
+        | SchemaFieldRel::Struct { name }
+        | SchemaFieldRel::Enum { name }
+        | SchemaFieldRel::Error { name }
+        | SchemaFieldRel::Union { name } => {
+            json!({ "$ref": format!("{}{name}", dialect.refs()) })
+        }
+        SchemaFieldRel::Map { value, .. } => json!({
+            "type": "object",
+            "additionalProperties": rel_schema(value, dialect),
+        }),
+        SchemaFieldRel::FixedArray { value, len } => json!({
+            "type": "array",
+            "items": rel_schema(value, dialect),
+            "minItems": len,
+            "maxItems": len,
+        }),
+        SchemaFieldRel::Tuple { items } => json!({
+            "type": "array",
+            "prefixItems": items.iter().map(|item| rel_schema(item, dialect)).collect::<Vec<_>>(),
+            "minItems": items.len(),
+            "maxItems": items.len(),
+        }),
+    }
+}
+
+/// Convert schema type into JSON Schema of the dialect
+pub(crate) fn type_schema(ty: &SchemaType, dialect: Dialect) -> Value {
+    let schema = match ty.ty {
+        SchemaTypes::Struct => struct_schema(ty, dialect),
+        SchemaTypes::Enum => enum_schema(ty),
+        SchemaTypes::Error => error_schema(ty, dialect),
+        SchemaTypes::Alias => alias_schema(ty, dialect),
+        SchemaTypes::Union => union_schema(ty, dialect),
+    };
+
+    with_description(schema, &ty.description)
+}
+
+/// Insert description into schema object
+pub(crate) fn with_description(mut schema: Value, description: &Option<String>) -> Value {
+    if let (Value::Object(map), Some(description)) = (&mut schema, description) {
+        map.insert(String::from("description"), json!(description));
+    }
+
+    schema
+}
+
+/// Mark schema object as deprecated
+fn with_deprecation(mut schema: Value, field: &SchemaField) -> Value {
+    if let (Value::Object(map), true) = (&mut schema, field.deprecated) {
+        map.insert(String::from("deprecated"), json!(true));
+    }
+
+    schema
+}
+
+/// Insert example and default value of the field into schema object
+fn with_values(mut schema: Value, field: &SchemaField) -> Value {
+    if let Value::Object(map) = &mut schema {
+        if let Some(example) = &field.example {
+            map.insert(String::from("examples"), json!([example]));
+        }
+
+        if let Some(default) = &field.default {
+            map.insert(String::from("default"), default.clone());
+        }
+    }
+
+    schema
+}
+
+/// Check if field can be omitted or null
+pub(crate) fn is_nullable(rel: &Option<SchemaFieldRel>) -> bool {
+    matches!(rel, None | Some(SchemaFieldRel::Nullable { .. }))
+}
+
+fn field_schema(field: &SchemaField, dialect: Dialect) -> Value {
+    match &field.rel {
+        Some(rel) => rel_schema(rel, dialect),
+        None => json!({}),
+    }
+}
+
+fn native_schema(ty: &SchemaFieldType, format: &SchemaFieldFormat, dialect: Dialect) -> Value {
+    let mut schema = Map::new();
+
+    let json_type = match ty {
+        SchemaFieldType::String | SchemaFieldType::Char => "string",
+        SchemaFieldType::Integer => "integer",
+        SchemaFieldType::Float => "number",
+        SchemaFieldType::Boolean => "boolean",
+        SchemaFieldType::Map | SchemaFieldType::Struct | SchemaFieldType::Enum | SchemaFieldType::Object => "object",
+    };
+    schema.insert(String::from("type"), json!(json_type));
+
+    if let SchemaFieldType::Char = ty {
+        schema.insert(String::from("minLength"), json!(1));
+        schema.insert(String::from("maxLength"), json!(1));
+    }
+
+    if let Some((minimum, maximum)) = integer_range(format) {
+        schema.insert(String::from("minimum"), minimum);
+        schema.insert(String::from("maximum"), maximum);
+    }
+
+    match format {
+        SchemaFieldFormat::Binary => {
+            schema.insert(String::from("contentEncoding"), json!("base64"));
+        }
+        format => {
+            if let Some(format) = format_name(format, dialect) {
+                schema.insert(String::from("format"), json!(format));
+            }
+        }
+    }
+
+    Value::Object(schema)
+}
+
+/// Range of integer format, 128-bit integers exceed JSON number range
+fn integer_range(format: &SchemaFieldFormat) -> Option<(Value, Value)> {
+    let range = match format {
+        SchemaFieldFormat::Int8 => (json!(i8::MIN), json!(i8::MAX)),
+        SchemaFieldFormat::Int16 => (json!(i16::MIN), json!(i16::MAX)),
+        SchemaFieldFormat::Int32 => (json!(i32::MIN), json!(i32::MAX)),
+        SchemaFieldFormat::Int64 | SchemaFieldFormat::Isize => (json!(i64::MIN), json!(i64::MAX)),
+        SchemaFieldFormat::UInt8 => (json!(u8::MIN), json!(u8::MAX)),
+        SchemaFieldFormat::UInt16 => (json!(u16::MIN), json!(u16::MAX)),
+        SchemaFieldFormat::UInt32 => (json!(u32::MIN), json!(u32::MAX)),
+        SchemaFieldFormat::UInt64 | SchemaFieldFormat::Usize => (json!(u64::MIN), json!(u64::MAX)),
+        _ => return None,
+    };
+
+    Some(range)
+}
+
+/// Format of native type, JSON Schema output has only formats registered by the specification,
+/// widths of integers are expressed by `minimum` and `maximum`
+fn format_name(format: &SchemaFieldFormat, dialect: Dialect) -> Option<&'static str> {
+    let name = match (format, dialect) {
+        (SchemaFieldFormat::Date, _) => "date",
+        (SchemaFieldFormat::DateTime, _) => "date-time",
+        (SchemaFieldFormat::Time, _) => "time",
+        (SchemaFieldFormat::Uuid, _) => "uuid",
+        (SchemaFieldFormat::Uri, _) => "uri",
+        (SchemaFieldFormat::Ipv4, _) => "ipv4",
+        (SchemaFieldFormat::Ipv6, _) => "ipv6",
+        // Formats defined by OpenAPI
+        (SchemaFieldFormat::Int32, Dialect::OpenApi) => "int32",
+        (SchemaFieldFormat::Int64 | SchemaFieldFormat::Isize, Dialect::OpenApi) => "int64",
+        (SchemaFieldFormat::Float32, Dialect::OpenApi) => "float",
+        (SchemaFieldFormat::Float64, Dialect::OpenApi) => "double",
+        _ => return None,
+    };
+
+    Some(name)
+}
+
+fn struct_schema(ty: &SchemaType, dialect: Dialect) -> Value {
+    let mut properties = Map::new();
+    let mut required = Vec::new();
+    let mut flattened = Vec::new();
+
+    for field in &ty.fields {
+        // Fields of flattened field are part of the struct
+        if field.flatten {
+            flattened.push(field_schema(field, dialect));
+            continue;
+        }
+
+        properties.insert(
+            field.name.clone(),
+            with_values(
+                with_deprecation(with_description(field_schema(field, dialect), &field.description), field),
+                field,
+            ),
+        );
+
+        if !field.optional && !is_nullable(&field.rel) {
+            required.push(field.name.clone());
+        }
+    }
+
+    let mut schema = json!({
+        "type": "object",
+        "properties": properties,
+        "required": required,
+    });
+
+    if flattened.is_empty() {
+        schema["title"] = json!(ty.name);
+
+        return schema;
+    }
+
+    flattened.insert(0, schema);
+
+    json!({
+        "title": ty.name,
+        "allOf": flattened,
+    })
+}
+
+fn alias_schema(ty: &SchemaType, dialect: Dialect) -> Value {
+    let mut schema = match ty.fields.first() {
+        Some(field) => field_schema(field, dialect),
+        None => json!({}),
+    };
+
+    if let Value::Object(map) = &mut schema {
+        map.insert(String::from("title"), json!(ty.name));
+    }
+
+    schema
+}
+
+fn union_schema(ty: &SchemaType, dialect: Dialect) -> Value {
+    let tagging = ty.tagging.clone().unwrap_or(SchemaTagging::External);
+
+    let variants = ty
+        .fields
+        .iter()
+        .map(|field| {
+            let tag = field.value.clone().unwrap_or_else(|| field.name.clone());
+            let payload = field.rel.as_ref().map(|rel| rel_schema(rel, dialect));
+
+            let variant = match (&tagging, payload) {
+                (SchemaTagging::External, None) => json!({ "const": tag }),
+                (SchemaTagging::External, Some(payload)) => json!({
+                    "type": "object",
+                    "properties": { tag.clone(): payload },
+                    "required": [tag],
+                    "additionalProperties": false,
+                }),
+                (SchemaTagging::Internal { tag: tag_name }, None) => json!({
+                    "type": "object",
+                    "properties": { tag_name.clone(): { "const": tag } },
+                    "required": [tag_name],
+                }),
+                (SchemaTagging::Internal { tag: tag_name }, Some(payload)) => json!({
+                    "allOf": [
+                        {
+                            "type": "object",
+                            "properties": { tag_name.clone(): { "const": tag } },
+                            "required": [tag_name],
+                        },
+                        payload,
+                    ],
+                }),
+                (SchemaTagging::Adjacent { tag: tag_name, .. }, None) => json!({
+                    "type": "object",
+                    "properties": { tag_name.clone(): { "const": tag } },
+                    "required": [tag_name],
+                }),
+                (SchemaTagging::Adjacent { tag: tag_name, content }, Some(payload)) => json!({
+                    "type": "object",
+                    "properties": {
+                        tag_name.clone(): { "const": tag },
+                        content.clone(): payload,
+                    },
+                    "required": [tag_name, content],
+                }),
+                (SchemaTagging::Untagged, None) => json!({ "type": "null" }),
+                (SchemaTagging::Untagged, Some(payload)) => payload,
+            };
+
+            with_deprecation(with_description(variant, &field.description), field)
+        })
+        .collect::<Vec<_>>();
+
+    json!({
+        "title": ty.name,
+        "oneOf": variants,
+    })
+}
+
+fn enum_schema(ty: &SchemaType) -> Value {
+    let values = ty
+        .fields
+        .iter()
+        .map(|field| {
+            let value = field.value.clone().unwrap_or_else(|| field.name.clone());
+
+            match &field.rel {
+                Some(SchemaFieldRel::Native { ty, .. }) => enum_value(ty, value),
+                _ => json!(value),
+            }
+        })
+        .collect::<Vec<_>>();
+
+    json!({
+        "title": ty.name,
+        "enum": values,
+    })
+}
+
+/// Convert enum value into JSON value of its type
+fn enum_value(ty: &SchemaFieldType, value: String) -> Value {
+    let parsed = match ty {
+        SchemaFieldType::Integer => value.parse::<i64>().ok().map(Value::from),
+        SchemaFieldType::Float => value.parse::<f64>().ok().map(Value::from),
+        SchemaFieldType::Boolean => value.parse::<bool>().ok().map(Value::from),
+        _ => None,
+    };
+
+    parsed.unwrap_or(Value::String(value))
+}
+
+fn error_schema(ty: &SchemaType, dialect: Dialect) -> Value {
+    let variants = ty
+        .fields
+        .iter()
+        .map(|field| {
+            let mut properties = Map::new();
+            properties.insert(String::from("code"), json!({ "const": field.value }));
+            properties.insert(String::from("httpCode"), json!({ "type": "integer" }));
+
+            let mut required = vec![String::from("code"), String::from("httpCode")];
+
+            if let Some(rel) = &field.rel {
+                properties.insert(String::from("payload"), rel_schema(rel, dialect));
+                required.push(String::from("payload"));
+            }
+
+            let variant = json!({
+                "title": field.name,
+                "type": "object",
+                "properties": properties,
+                "required": required,
+            });
+
+            with_deprecation(with_description(variant, &field.description), field)
+        })
+        .collect::<Vec<_>>();
+
+    json!({
+        "title": ty.name,
+        "oneOf": variants,
+    })
+}
@@ -0,0 +1,4 @@
+//! Exporters of the schema into other formats
+
+pub mod json_schema;
+pub mod openapi;
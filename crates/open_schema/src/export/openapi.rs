@@ -0,0 +1,131 @@
+use serde::Serialize;
+use serde_json::{json, Map, Value};
+
+use crate::{
+    applike::AppInfoLike,
+    schema::{SchemaFieldRel, SchemaRoot},
+    SchemaProcedure, SCHEMA_PATH_SEPARATOR,
+};
+
+use super::json_schema::{is_nullable, rel_schema, type_schema, Dialect};
+
+pub const OPENAPI_VERSION: &str = "3.1.0";
+
+const JSON_CONTENT_TYPE: &str = "application/json";
+
+/// Convert schema into OpenAPI 3.1 document
+///
+/// Every procedure is POST operation under its path, types are component schemas.
+pub fn to_openapi<I>(root: &SchemaRoot<I>) -> Value
+where
+    I: AppInfoLike + Serialize,
+{
+    let mut paths = Map::new();
+    for procedure in &root.procedures {
+        paths.insert(operation_path(procedure), json!({ "post": operation(procedure) }));
+    }
+
+    let schemas = root
+        .types
+        .iter()
+        .map(|(name, ty)| (name.clone(), type_schema(ty, Dialect::OpenApi)))
+        .collect::<Map<_, _>>();
+
+    json!({
+        "openapi": OPENAPI_VERSION,
+        "info": {
+            "title": root.info.name(),
+            "version": root.info.version(),
+            "description": root.info.description(),
+        },
+        "paths": paths,
+        "components": {
+            "schemas": schemas,
+        },
+    })
+}
+
+fn operation_path(procedure: &SchemaProcedure) -> String {
+    format!("{SCHEMA_PATH_SEPARATOR}{}", procedure.full_path())
+}
+
+fn operation(procedure: &SchemaProcedure) -> Value {
+    let mut operation = Map::new();
+
+    let operation_id = operation_path(procedure)
+        .trim_start_matches(SCHEMA_PATH_SEPARATOR)
+        .replace(SCHEMA_PATH_SEPARATOR, "_");
+    operation.insert(String::from("operationId"), json!(operation_id));
+
+    if let Some(description) = &procedure.description {
+        operation.insert(String::from("description"), json!(description));
+    }
+
+    if procedure.deprecated {
+        operation.insert(String::from("deprecated"), json!(true));
+    }
+
+    if !procedure.path.is_empty() {
+        operation.insert(String::from("tags"), json!([procedure.path]));
+    }
+
+    operation.insert(String::from("x-rpc-id"), json!(procedure.id));
+    operation.insert(String::from("x-rpc-type"), json!(procedure.ty));
+
+    if let Some(params) = &procedure.params {
+        operation.insert(
+            String::from("requestBody"),
+            json!({
+                "required": !is_nullable(&procedure.params),
+                "content": content(params, procedure.examples.iter().map(|example| &example.params)),
+            }),
+        );
+    }
+
+    let mut responses = Map::new();
+
+    let mut ok = Map::new();
+    ok.insert(String::from("description"), json!("Result of the procedure"));
+    if let Some(result) = &procedure.result {
+        ok.insert(
+            String::from("content"),
+            content(result, procedure.examples.iter().map(|example| &example.result)),
+        );
+    }
+    responses.insert(String::from("200"), Value::Object(ok));
+
+    let mut err = Map::new();
+    err.insert(String::from("description"), json!("Error of the procedure"));
+    if let Some(error) = &procedure.error {
+        err.insert(String::from("content"), content(error, []));
+    }
+    responses.insert(String::from("default"), Value::Object(err));
+
+    operation.insert(String::from("responses"), Value::Object(responses));
+
+    if !procedure.errors.is_empty() {
+        let codes = procedure.errors.iter().map(|error| &error.code).collect::<Vec<_>>();
+        operation.insert(String::from("x-rpc-errors"), json!(codes));
+    }
+
+    Value::Object(operation)
+}
+
+fn content<'a>(rel: &SchemaFieldRel, examples: impl IntoIterator<Item = &'a Value>) -> Value {
+    let mut media = Map::new();
+    media.insert(String::from("schema"), rel_schema(rel, Dialect::OpenApi));
+
+    let examples = examples
+        .into_iter()
+        .enumerate()
+        .map(|(i, example)| (format!("example{}", i + 1), json!({ "value": example })))
+        .collect::<Map<_, _>>();
+
+    if !examples.is_empty() {
+        media.insert(String::from("examples"), Value::Object(examples));
+    }
+
+    json!({
+        JSON_CONTENT_TYPE: media,
+    })
+}
@@ -0,0 +1,7 @@
+use super::impl_native;
+
+// Bytes are binary payload, like `Bytes` of this crate
+impl_native!(
+    bytes::Bytes => (String, Binary),
+    bytes::BytesMut => (String, Binary),
+);
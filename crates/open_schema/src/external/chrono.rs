@@ -0,0 +1,12 @@
+use chrono::{DateTime, FixedOffset, Local, NaiveDate, NaiveDateTime, NaiveTime, Utc};
+
+use super::impl_native;
+
+impl_native!(
+    NaiveDate => (String, Date),
+    NaiveTime => (String, Time),
+    NaiveDateTime => (String, LocalDateTime),
+    DateTime<Utc> => (String, DateTime),
+    DateTime<FixedOffset> => (String, DateTime),
+    DateTime<Local> => (String, DateTime),
+);
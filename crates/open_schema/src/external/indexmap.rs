@@ -0,0 +1,90 @@
+use indexmap::{IndexMap, IndexSet};
+
+use crate::{
+    schema::{FieldFormat, FieldType, SchemaField, SchemaFieldFormat, SchemaFieldRel, SchemaFieldType, TypeMapRef},
+    schemable::schemable_field::explore_field_rel,
+    SchemaProcedure, SchemableField, SchemableParams, SchemableResult,
+};
+
+impl<K, V> FieldType for IndexMap<K, V> {
+    #[inline]
+    fn field_type() -> SchemaFieldType {
+        SchemaFieldType::Map
+    }
+}
+
+impl<K, V> FieldFormat for IndexMap<K, V> {
+    #[inline]
+    fn field_format() -> SchemaFieldFormat {
+        SchemaFieldFormat::IndexMap
+    }
+}
+
+impl<K, V> SchemableField for IndexMap<K, V>
+where
+    K: SchemableField,
+    V: SchemableField,
+{
+    fn get_rel_type() -> SchemaFieldRel {
+        SchemaFieldRel::Map {
+            key: Box::new(K::get_rel_type()),
+            value: Box::new(V::get_rel_type()),
+        }
+    }
+
+    fn explore_type(field: &mut SchemaField, type_map: TypeMapRef) {
+        K::explore_type(field, type_map.clone());
+        V::explore_type(field, type_map);
+
+        field.rel = Some(IndexMap::<K, V>::get_rel_type());
+    }
+}
+
+impl<T: SchemableField> SchemableField for IndexSet<T> {
+    fn get_rel_type() -> SchemaFieldRel {
+        SchemaFieldRel::Array {
+            value: Box::new(T::get_rel_type()),
+        }
+    }
+
+    fn explore_type(field: &mut SchemaField, type_map: TypeMapRef) {
+        T::explore_type(field, type_map);
+        field.rel = Some(IndexSet::<T>::get_rel_type());
+    }
+}
+
+impl<K, V> SchemableParams for IndexMap<K, V>
+where
+    K: SchemableField,
+    V: SchemableField,
+{
+    #[inline]
+    fn apply_schema(proc: &mut SchemaProcedure, type_map: TypeMapRef) {
+        proc.params = explore_field_rel::<Self>(type_map);
+    }
+}
+
+impl<K, V> SchemableResult for IndexMap<K, V>
+where
+    K: SchemableField,
+    V: SchemableField,
+{
+    #[inline]
+    fn apply_schema(proc: &mut SchemaProcedure, type_map: TypeMapRef) {
+        proc.result = explore_field_rel::<Self>(type_map);
+    }
+}
+
+impl<T: SchemableField> SchemableParams for IndexSet<T> {
+    #[inline]
+    fn apply_schema(proc: &mut SchemaProcedure, type_map: TypeMapRef) {
+        proc.params = explore_field_rel::<Self>(type_map);
+    }
+}
+
+impl<T: SchemableField> SchemableResult for IndexSet<T> {
+    #[inline]
+    fn apply_schema(proc: &mut SchemaProcedure, type_map: TypeMapRef) {
+        proc.result = explore_field_rel::<Self>(type_map);
+    }
+}
@@ -0,0 +1,9 @@
+use ipnet::{IpNet, Ipv4Net, Ipv6Net};
+
+use super::impl_native;
+
+impl_native!(
+    IpNet => (String, IpNet),
+    Ipv4Net => (String, IpNet),
+    Ipv6Net => (String, IpNet),
+);
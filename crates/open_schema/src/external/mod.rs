@@ -0,0 +1,72 @@
+//! Schema of types from std and other crates
+//!
+//! Types of other crates are enabled by cargo features of the same name,
+//! types of std are always enabled.
+
+#[cfg(feature = "bytes")]
+mod bytes;
+#[cfg(feature = "chrono")]
+mod chrono;
+#[cfg(feature = "indexmap")]
+mod indexmap;
+#[cfg(feature = "ipnet")]
+mod ipnet;
+#[cfg(feature = "rust_decimal")]
+mod rust_decimal;
+#[cfg(feature = "smallvec")]
+mod smallvec;
+mod stdlib;
+#[cfg(feature = "url")]
+mod url;
+#[cfg(feature = "uuid")]
+mod uuid;
+
+/// Implement schema traits for types serialized as native JSON value
+macro_rules! impl_native {
+    ($($t:ty => ($ty:ident, $format:ident)),* $(,)?) => {
+        $(
+            impl crate::schema::FieldType for $t {
+                #[inline]
+                fn field_type() -> crate::schema::SchemaFieldType {
+                    crate::schema::SchemaFieldType::$ty
+                }
+            }
+
+            impl crate::schema::FieldFormat for $t {
+                #[inline]
+                fn field_format() -> crate::schema::SchemaFieldFormat {
+                    crate::schema::SchemaFieldFormat::$format
+                }
+            }
+
+            impl crate::SchemableField for $t {
+                fn get_rel_type() -> crate::schema::SchemaFieldRel {
+                    crate::schema::SchemaFieldRel::Native {
+                        ty: <$t as crate::schema::FieldType>::field_type(),
+                        format: <$t as crate::schema::FieldFormat>::field_format(),
+                    }
+                }
+
+                fn explore_type(field: &mut crate::schema::SchemaField, _type_map: crate::schema::TypeMapRef) {
+                    field.rel = Some(<$t as crate::SchemableField>::get_rel_type());
+                }
+            }
+
+            impl crate::SchemableParams for $t {
+                #[inline]
+                fn apply_schema(proc: &mut crate::SchemaProcedure, _: crate::schema::TypeMapRef) {
+                    proc.params = Some(<$t as crate::SchemableField>::get_rel_type());
+                }
+            }
+
+            impl crate::SchemableResult for $t {
+                #[inline]
+                fn apply_schema(proc: &mut crate::SchemaProcedure, _: crate::schema::TypeMapRef) {
+                    proc.result = Some(<$t as crate::SchemableField>::get_rel_type());
+                }
+            }
+        )*
+    };
+}
+
+pub(crate) use impl_native;
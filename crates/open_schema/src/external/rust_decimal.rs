@@ -0,0 +1,4 @@
+use super::impl_native;
+
+// Decimal is serialized as string, to keep its precision
+impl_native!(rust_decimal::Decimal => (String, Decimal));
@@ -0,0 +1,46 @@
+use smallvec::{Array, SmallVec};
+
+use crate::{
+    schema::{SchemaField, SchemaFieldRel, TypeMapRef},
+    schemable::schemable_field::explore_field_rel,
+    SchemaProcedure, SchemableField, SchemableParams, SchemableResult,
+};
+
+impl<A> SchemableField for SmallVec<A>
+where
+    A: Array,
+    A::Item: SchemableField,
+{
+    fn get_rel_type() -> SchemaFieldRel {
+        SchemaFieldRel::Array {
+            value: Box::new(A::Item::get_rel_type()),
+        }
+    }
+
+    fn explore_type(field: &mut SchemaField, type_map: TypeMapRef) {
+        A::Item::explore_type(field, type_map);
+        field.rel = Some(SmallVec::<A>::get_rel_type());
+    }
+}
+
+impl<A> SchemableParams for SmallVec<A>
+where
+    A: Array,
+    A::Item: SchemableField,
+{
+    #[inline]
+    fn apply_schema(proc: &mut SchemaProcedure, type_map: TypeMapRef) {
+        proc.params = explore_field_rel::<Self>(type_map);
+    }
+}
+
+impl<A> SchemableResult for SmallVec<A>
+where
+    A: Array,
+    A::Item: SchemableField,
+{
+    #[inline]
+    fn apply_schema(proc: &mut SchemaProcedure, type_map: TypeMapRef) {
+        proc.result = explore_field_rel::<Self>(type_map);
+    }
+}
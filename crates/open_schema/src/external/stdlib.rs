@@ -0,0 +1,64 @@
+use std::{
+    net::{IpAddr, Ipv4Addr, Ipv6Addr},
+    num::{
+        NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128, NonZeroU16,
+        NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize,
+    },
+};
+
+use crate::{
+    schema::{SchemaField, SchemaFieldRel, TypeMapRef},
+    schemable::schemable_field::explore_field_rel,
+    SchemaProcedure, SchemableField, SchemableParams, SchemableResult,
+};
+
+use super::impl_native;
+
+impl_native!(
+    char => (Char, Type),
+
+    // Network addresses
+    IpAddr => (String, Ip),
+    Ipv4Addr => (String, Ipv4),
+    Ipv6Addr => (String, Ipv6),
+
+    // Non-zero ints, range of the width would include zero, so they are plain integers
+    NonZeroI8 => (Integer, Type),
+    NonZeroI16 => (Integer, Type),
+    NonZeroI32 => (Integer, Type),
+    NonZeroI64 => (Integer, Type),
+    NonZeroI128 => (Integer, Type),
+    NonZeroIsize => (Integer, Type),
+    NonZeroU8 => (Integer, Type),
+    NonZeroU16 => (Integer, Type),
+    NonZeroU32 => (Integer, Type),
+    NonZeroU64 => (Integer, Type),
+    NonZeroU128 => (Integer, Type),
+    NonZeroUsize => (Integer, Type),
+);
+
+impl<T: SchemableField, const N: usize> SchemableField for [T; N] {
+    fn get_rel_type() -> SchemaFieldRel {
+        SchemaFieldRel::FixedArray {
+            value: Box::new(T::get_rel_type()),
+            len: N,
+        }
+    }
+
+    fn explore_type(field: &mut SchemaField, type_map: TypeMapRef) {
+        T::explore_type(field, type_map);
+        field.rel = Some(<[T; N]>::get_rel_type());
+    }
+}
+
+impl<T: SchemableField, const N: usize> SchemableParams for [T; N] {
+    fn apply_schema(proc: &mut SchemaProcedure, type_map: TypeMapRef) {
+        proc.params = explore_field_rel::<Self>(type_map);
+    }
+}
+
+impl<T: SchemableField, const N: usize> SchemableResult for [T; N] {
+    fn apply_schema(proc: &mut SchemaProcedure, type_map: TypeMapRef) {
+        proc.result = explore_field_rel::<Self>(type_map);
+    }
+}
@@ -0,0 +1,3 @@
+use super::impl_native;
+
+impl_native!(url::Url => (String, Uri));
@@ -0,0 +1,3 @@
+use super::impl_native;
+
+impl_native!(uuid::Uuid => (String, Uuid));
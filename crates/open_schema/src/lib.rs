@@ -1,18 +1,27 @@
 pub use crate::{
+    bytes::Bytes,
     schema::SchemaProcedure,
     schemable::{
-        schemable_field::SchemableField, schemable_params::SchemableParams, schemable_result::SchemableResult,
-        schemable_type::SchemableType,
+        schemable_error::SchemableError, schemable_field::SchemableField, schemable_params::SchemableParams,
+        schemable_result::SchemableResult, schemable_type::SchemableType,
     },
 };
 
 pub use rpc_macros::Schemable;
 
+/// Serde used by code generated by derive macros, so user crates do not need to depend on it
+#[doc(hidden)]
+pub use serde;
+
 pub mod applike;
+pub mod bytes;
+pub mod compat;
+pub mod export;
 pub mod procedurelike;
 pub mod schema;
 
 pub const SCHEMA_VERSION: &'static str = "2.0.0";
 pub const SCHEMA_PATH_SEPARATOR: &'static str = "/";
 
+mod external;
 mod schemable;
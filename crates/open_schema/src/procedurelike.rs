@@ -1,5 +1,5 @@
 use crate::{
-    schema::{procedure_type::ProcedureType, TypeMapRef},
+    schema::{procedure_type::ProcedureType, SchemaExample, TypeMapRef},
     SchemaProcedure,
 };
 
@@ -7,6 +7,10 @@ pub trait ProcedureLike {
     fn id(&self) -> usize;
     fn procedure_type(&self) -> ProcedureType;
     fn name(&self) -> &str;
+    fn description(&self) -> Option<&str>;
+    /// Note of deprecated procedure, `None` if procedure is not deprecated
+    fn deprecation(&self) -> Option<&str>;
+    fn examples(&self) -> &[SchemaExample];
     fn call_schema(
         &self,
         procedure_schema: SchemaProcedure,
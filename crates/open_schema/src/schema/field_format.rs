@@ -1,5 +1,7 @@
 use std::sync::Arc;
 
+use crate::Bytes;
+
 use super::SchemaFieldFormat;
 
 pub trait FieldFormat {
@@ -42,7 +44,10 @@ impl_field_format!(
     // Time
     { std::time::Duration, UInt64 },
     { time::Date, Date },
-    { time::OffsetDateTime, DateTime }
+    { time::OffsetDateTime, DateTime },
+
+    // Binary
+    { Bytes, Binary }
 );
 
 macro_rules! impl_field_format {
@@ -1,5 +1,7 @@
 use std::sync::Arc;
 
+use crate::Bytes;
+
 use super::SchemaFieldType;
 
 pub trait FieldType {
@@ -20,7 +22,7 @@ macro_rules! impl_field_type {
 }
 
 impl_field_type!(Boolean, { bool });
-impl_field_type!(String, { String, str, &str, Arc<str>, time::Date, time::OffsetDateTime });
+impl_field_type!(String, { String, str, &str, Arc<str>, time::Date, time::OffsetDateTime, Bytes });
 impl_field_type!(Integer, { i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, std::time::Duration });
 impl_field_type!(Float, { f32, f64 });
 impl_field_type!(Object, { serde_json::Value });
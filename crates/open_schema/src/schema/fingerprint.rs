@@ -0,0 +1,121 @@
+use serde_json::{json, Value};
+use sha2::{Digest, Sha256};
+
+use super::{ErrorMap, SchemaField, SchemaProcedure, TypeMap};
+
+/// Wire contract of the schema, without documentation, examples and deprecation
+pub(crate) fn contract(rpcapi: &str, procedures: &[SchemaProcedure], types: &TypeMap, errors: &ErrorMap) -> Value {
+    let procedures = procedures
+        .iter()
+        .map(|procedure| {
+            json!({
+                "id": procedure.id,
+                "type": procedure.ty,
+                "path": procedure.path,
+                "name": procedure.name,
+                "params": procedure.params,
+                "result": procedure.result,
+                "error": procedure.error,
+                "errors": procedure
+                    .errors
+                    .iter()
+                    .map(|error| json!({ "code": error.code, "httpCode": error.http_code }))
+                    .collect::<Vec<_>>(),
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let types = types
+        .iter()
+        .map(|(name, ty)| {
+            let contract = json!({
+                "type": ty.ty,
+                "tagging": ty.tagging,
+                "fields": ty.fields.iter().map(field_contract).collect::<Vec<_>>(),
+            });
+
+            (name.clone(), contract)
+        })
+        .collect::<serde_json::Map<_, _>>();
+
+    let errors = errors
+        .values()
+        .map(|error| {
+            json!({
+                "code": error.code,
+                "httpCode": error.http_code,
+                "payload": error.payload,
+            })
+        })
+        .collect::<Vec<_>>();
+
+    json!({
+        "rpcapi": rpcapi,
+        "procedures": procedures,
+        "types": types,
+        "errors": errors,
+    })
+}
+
+/// Wire contract of the field, nullability is part of its relation,
+/// aliases are names accepted in input
+fn field_contract(field: &SchemaField) -> Value {
+    json!({
+        "name": field.name,
+        "aliases": field.aliases,
+        "rel": field.rel,
+        "value": field.value,
+        "optional": field.optional,
+        "flatten": field.flatten,
+    })
+}
+
+/// SHA-256 of canonical JSON of the value, in lowercase hex
+pub(crate) fn fingerprint(value: &Value) -> String {
+    let mut canonical = String::new();
+    write_canonical(value, &mut canonical);
+
+    let digest = Sha256::digest(canonical.as_bytes());
+
+    digest.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+/// Write JSON with keys of objects sorted and without whitespace,
+/// so the same schema gives the same text regardless of map implementation
+fn write_canonical(value: &Value, out: &mut String) {
+    match value {
+        Value::Array(items) => {
+            out.push('[');
+
+            for (i, item) in items.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+
+                write_canonical(item, out);
+            }
+
+            out.push(']');
+        }
+        Value::Object(map) => {
+            let mut keys = map.keys().collect::<Vec<_>>();
+            keys.sort();
+
+            out.push('{');
+
+            for (i, key) in keys.into_iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+
+                out.push_str(&Value::from(key.as_str()).to_string());
+                out.push(':');
+                write_canonical(&map[key], out);
+            }
+
+            out.push('}');
+        }
+        // Scalars are written the same way by every serializer
+        _ => out.push_str(&value.to_string()),
+    }
+}
@@ -2,18 +2,23 @@ pub use crate::schema::{
     field_format::FieldFormat,
     field_type::FieldType,
     procedure_type::{ProcedureType, ProcedureTypeTrait},
-    schema_field::SchemaField,
+    schema_error::{new_error_map, ErrorMap, SchemaError},
+    schema_field::{field_value, SchemaField},
     schema_field_format::SchemaFieldFormat,
     schema_field_rel::SchemaFieldRel,
     schema_field_type::SchemaFieldType,
-    schema_procedure::SchemaProcedure,
-    schema_root::{insert_into_type_map_ref, new_type_map_ref, SchemaRoot, TypeMapRef},
-    schema_type::{SchemaType, SchemaTypes},
+    schema_procedure::{SchemaExample, SchemaProcedure},
+    schema_root::{
+        insert_fields_into_type_map_ref, insert_into_type_map_ref, new_type_map_ref, SchemaRoot, TypeMap, TypeMapRef,
+    },
+    schema_type::{SchemaTagging, SchemaType, SchemaTypes},
 };
 
 pub(crate) mod field_format;
 pub(crate) mod field_type;
+pub(crate) mod fingerprint;
 pub(crate) mod procedure_type;
+pub(crate) mod schema_error;
 pub(crate) mod schema_field;
 pub(crate) mod schema_field_format;
 pub(crate) mod schema_field_rel;
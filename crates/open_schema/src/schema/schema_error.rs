@@ -0,0 +1,24 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::SchemaFieldRel;
+
+pub type ErrorMap = BTreeMap<String, SchemaError>;
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct SchemaError {
+    /// Name of the error type
+    pub name: String,
+    /// Name of the error variant
+    pub variant: String,
+    pub code: String,
+    pub http_code: u16,
+    /// Type of the error payload
+    pub payload: Option<SchemaFieldRel>,
+}
+
+pub fn new_error_map() -> ErrorMap {
+    BTreeMap::new()
+}
@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
 #[serde(rename_all = "lowercase")]
 pub enum SchemaFieldFormat {
     /// Same as field type
@@ -41,6 +41,28 @@ pub enum SchemaFieldFormat {
     HashMap,
     /// Map of btree map type
     BTreeMap,
+    /// String of base64 encoded bytes, raw bytes in binary codecs
+    Binary,
+    /// String of time without date
+    Time,
+    /// String of date-time without time zone
+    LocalDateTime,
+    /// String of UUID
+    Uuid,
+    /// String of arbitrary precision decimal number
+    Decimal,
+    /// String of URI
+    Uri,
+    /// String of IPv4 or IPv6 address
+    Ip,
+    /// String of IPv4 address
+    Ipv4,
+    /// String of IPv6 address
+    Ipv6,
+    /// String of network in CIDR notation
+    IpNet,
+    /// Map of index map type, keeps insertion order
+    IndexMap,
 }
 
 impl SchemaFieldFormat {
@@ -65,6 +87,17 @@ impl SchemaFieldFormat {
             "datetime" => Self::DateTime,
             "hashmap" => Self::HashMap,
             "btreemap" => Self::BTreeMap,
+            "binary" => Self::Binary,
+            "time" => Self::Time,
+            "localdatetime" => Self::LocalDateTime,
+            "uuid" => Self::Uuid,
+            "decimal" => Self::Decimal,
+            "uri" => Self::Uri,
+            "ip" => Self::Ip,
+            "ipv4" => Self::Ipv4,
+            "ipv6" => Self::Ipv6,
+            "ipnet" => Self::IpNet,
+            "indexmap" => Self::IndexMap,
             _ => panic!("Unsupported format"),
         }
     }
@@ -2,7 +2,7 @@ use serde::{Deserialize, Serialize};
 
 use super::{SchemaFieldFormat, SchemaFieldType};
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase", tag = "variant")]
 pub enum SchemaFieldRel {
     /// Native types
@@ -17,13 +17,51 @@ pub enum SchemaFieldRel {
     Nullable { value: Box<SchemaFieldRel> },
     /// Custom type
     Type { name: String, ty: Box<SchemaFieldRel> },
+    /// Named alias of newtype or tuple struct, aliased type is in type map
+    Alias { name: String },
     /// Struct type
     Struct { name: String },
     /// Enum type
     Enum { name: String },
+    /// Typed error type
+    Error { name: String },
+    /// Discriminated union type
+    Union { name: String },
     /// Map type - key and value are boxed
     Map {
         key: Box<SchemaFieldRel>,
         value: Box<SchemaFieldRel>,
     },
+    /// Fixed-length tuple type
+    Tuple { items: Vec<SchemaFieldRel> },
+    /// Fixed-size array type
+    FixedArray { value: Box<SchemaFieldRel>, len: usize },
+}
+
+impl SchemaFieldRel {
+    /// Name of the related type, used in names of generic type instances, eg. `Page_User`
+    pub fn type_name(&self) -> String {
+        match self {
+            Self::Native {
+                ty,
+                format: SchemaFieldFormat::Type,
+            } => format!("{ty:?}"),
+            Self::Native { format, .. } => format!("{format:?}"),
+            Self::Array { value } => format!("Array_{}", value.type_name()),
+            Self::Nullable { value } => format!("Nullable_{}", value.type_name()),
+            Self::Type { name, .. }
+            | Self::Alias { name }
+            | Self::Struct { name }
+            | Self::Enum { name }
+            | Self::Error { name }
+            | Self::Union { name } => name.clone(),
+            Self::Map { key, value } => format!("Map_{}_{}", key.type_name(), value.type_name()),
+            Self::FixedArray { value, len } => format!("Array{len}_{}", value.type_name()),
+            Self::Tuple { items } => {
+                let items = items.iter().map(|item| item.type_name()).collect::<Vec<_>>();
+
+                format!("Tuple_{}", items.join("_"))
+            }
+        }
+    }
 }
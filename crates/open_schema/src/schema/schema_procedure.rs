@@ -1,8 +1,9 @@
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 
-use crate::procedurelike::ProcedureLike;
+use crate::{procedurelike::ProcedureLike, SCHEMA_PATH_SEPARATOR};
 
-use super::{procedure_type::ProcedureType, SchemaFieldRel, TypeMapRef};
+use super::{procedure_type::ProcedureType, SchemaError, SchemaFieldRel, TypeMapRef};
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
 #[serde(rename_all = "camelCase")]
@@ -14,6 +15,28 @@ pub struct SchemaProcedure {
     pub name: String,
     pub params: Option<SchemaFieldRel>,
     pub result: Option<SchemaFieldRel>,
+    /// Error returned by procedure
+    pub error: Option<SchemaFieldRel>,
+    /// Typed errors that procedure can return
+    #[serde(default)]
+    pub errors: Vec<SchemaError>,
+    /// Description given at registration
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub deprecated: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deprecation_note: Option<String>,
+    /// Examples of calls given at registration
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub examples: Vec<SchemaExample>,
+}
+
+/// Example call of procedure, params and result are serialized as they are sent
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct SchemaExample {
+    pub params: Value,
+    pub result: Value,
 }
 
 impl SchemaProcedure {
@@ -32,11 +55,17 @@ impl SchemaProcedure {
             name,
             params,
             result,
+            error: None,
+            errors: Vec::new(),
+            description: None,
+            deprecated: false,
+            deprecation_note: None,
+            examples: Vec::new(),
         }
     }
 
     pub fn from_app<P: ProcedureLike>(path: String, app_proc: P, type_map: TypeMapRef) -> Self {
-        let schema = Self::new(
+        let mut schema = Self::new(
             app_proc.id(),
             app_proc.procedure_type(),
             path,
@@ -44,7 +73,23 @@ impl SchemaProcedure {
             None,
             None,
         );
+        schema.description = app_proc.description().map(String::from);
+        schema.examples = app_proc.examples().to_vec();
+
+        if let Some(note) = app_proc.deprecation() {
+            schema.deprecated = true;
+            schema.deprecation_note = Some(note.to_string()).filter(|note| !note.is_empty());
+        }
 
         app_proc.call_schema(schema, type_map)
     }
+
+    /// Path of the procedure including its name, eg. `users/get_user`
+    pub fn full_path(&self) -> String {
+        if self.path.is_empty() {
+            return self.name.clone();
+        }
+
+        format!("{}{SCHEMA_PATH_SEPARATOR}{}", self.path, self.name)
+    }
 }
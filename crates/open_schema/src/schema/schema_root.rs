@@ -4,24 +4,53 @@ use serde::{Deserialize, Serialize};
 
 use crate::{applike::AppInfoLike, SchemableType};
 
-use super::{schema_type::SchemaTypes, SchemaProcedure, SchemaType};
+use super::{
+    fingerprint::{contract, fingerprint},
+    schema_type::SchemaTypes,
+    ErrorMap, SchemaField, SchemaProcedure, SchemaType,
+};
 
 pub type TypeMap = BTreeMap<String, SchemaType>;
 pub type TypeMapRef = Rc<Mutex<TypeMap>>;
 
+/// Insert type into type map, if it is not there yet
+///
+/// The entry is reserved before fields are explored, so recursive types
+/// like `struct Node { children: Vec<Node> }` and mutually recursive types
+/// stop at the reserved entry instead of exploring themselves forever.
 pub fn insert_into_type_map_ref<T: SchemableType>(ty: SchemaTypes, map: TypeMapRef) {
     let name = T::type_name();
 
-    let contains = map.lock().unwrap().contains_key(&name);
-    if contains {
-        return;
+    {
+        let mut map = map.lock().unwrap();
+        if map.contains_key(&name) {
+            return;
+        }
+
+        map.insert(name.clone(), SchemaType::new(name.clone(), ty.clone(), Vec::new(), None));
     }
 
     let fields = T::type_fields(map.clone());
+    let description = T::type_description();
+
+    let mut schema_type = SchemaType::new(name.clone(), ty, fields, description);
+    schema_type.tagging = T::type_tagging();
+
+    map.lock().unwrap().insert(name, schema_type);
+}
 
+/// Insert type that has no Rust type of its own, eg. struct variant of enum
+pub fn insert_fields_into_type_map_ref(
+    name: String,
+    ty: SchemaTypes,
+    fields: Vec<SchemaField>,
+    description: Option<String>,
+    map: TypeMapRef,
+) {
     map.lock()
         .unwrap()
-        .insert(name.clone(), SchemaType::new(name, ty, fields));
+        .entry(name.clone())
+        .or_insert_with(|| SchemaType::new(name, ty, fields, description));
 }
 
 #[derive(Serialize, Deserialize)]
@@ -31,9 +60,41 @@ where
     I: AppInfoLike + Serialize,
 {
     pub rpcapi: String,
+    /// Fingerprint of procedures, types and errors, see `SchemaRoot::compute_fingerprint`
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub fingerprint: String,
     pub info: I,
     pub procedures: Vec<SchemaProcedure>,
     pub types: TypeMap,
+    /// Catalogue of typed errors, by error code
+    #[serde(default)]
+    pub errors: ErrorMap,
+}
+
+impl<I> SchemaRoot<I>
+where
+    I: AppInfoLike + Serialize,
+{
+    /// Deterministic fingerprint of the schema, SHA-256 of canonical JSON of its wire contract
+    ///
+    /// Only names, ids, relations, fields with their aliases and error codes with HTTP codes are hashed.
+    /// Info of the app, descriptions, examples and deprecation are left out,
+    /// so editing docs does not lock out clients.
+    pub fn compute_fingerprint(&self) -> String {
+        fingerprint(&contract(&self.rpcapi, &self.procedures, &self.types, &self.errors))
+    }
+
+    /// Convert schema into OpenAPI 3.1 document
+    #[inline]
+    pub fn to_openapi(&self) -> serde_json::Value {
+        crate::export::openapi::to_openapi(self)
+    }
+
+    /// Convert types into JSON Schema (draft 2020-12) bundle
+    #[inline]
+    pub fn to_json_schema(&self) -> serde_json::Value {
+        crate::export::json_schema::to_json_schema(&self.types)
+    }
 }
 
 impl<I> From<SchemaRoot<I>> for serde_json::Value
@@ -2,11 +2,31 @@ use serde::{Deserialize, Serialize};
 
 use super::SchemaField;
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum SchemaTypes {
     Enum,
     Struct,
+    /// Typed error, fields are variants with error code as value
+    Error,
+    /// Named alias of newtype or tuple struct, the only field has aliased type
+    Alias,
+    /// Discriminated union of data-carrying enum, fields are variants with their payload
+    Union,
+}
+
+/// Serde representation of union variants
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", tag = "kind")]
+pub enum SchemaTagging {
+    /// `{"Variant": payload}`, unit variant is `"Variant"`
+    External,
+    /// `{"tag": "Variant", ...payload}`
+    Internal { tag: String },
+    /// `{"tag": "Variant", "content": payload}`
+    Adjacent { tag: String, content: String },
+    /// Only payload, unit variant is `null`
+    Untagged,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -16,10 +36,22 @@ pub struct SchemaType {
     #[serde(rename = "type")]
     pub ty: SchemaTypes,
     pub fields: Vec<SchemaField>,
+    /// Doc comment of the type
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Representation of variants, for unions
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tagging: Option<SchemaTagging>,
 }
 
 impl SchemaType {
-    pub(crate) fn new(name: String, ty: SchemaTypes, fields: Vec<SchemaField>) -> Self {
-        Self { name, ty, fields }
+    pub(crate) fn new(name: String, ty: SchemaTypes, fields: Vec<SchemaField>, description: Option<String>) -> Self {
+        Self {
+            name,
+            ty,
+            fields,
+            description,
+            tagging: None,
+        }
     }
 }
@@ -1,3 +1,4 @@
+pub mod schemable_error;
 pub mod schemable_field;
 pub mod schemable_params;
 pub mod schemable_result;
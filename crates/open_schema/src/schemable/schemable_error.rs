@@ -0,0 +1,34 @@
+use std::sync::Arc;
+
+use crate::{
+    schema::{insert_into_type_map_ref, SchemaTypes, TypeMapRef},
+    SchemaProcedure, SchemableField,
+};
+
+pub trait SchemableError {
+    fn apply_schema(proc: &mut SchemaProcedure, type_map: TypeMapRef);
+}
+
+macro_rules! factory_error {
+    ($($t:ty),*) => {
+        $(
+            impl SchemableError for $t {
+                #[inline]
+                fn apply_schema(proc: &mut SchemaProcedure, _: TypeMapRef) {
+                    proc.error = Some(<$t as SchemableField>::get_rel_type());
+                }
+            }
+        )*
+    };
+}
+
+factory_error!(String, &str, Arc<str>, serde_json::Value);
+
+impl SchemableError for errs::Error {
+    fn apply_schema(proc: &mut SchemaProcedure, type_map: TypeMapRef) {
+        proc.error = Some(<errs::Error as SchemableField>::get_rel_type());
+
+        // Insert standard error into type map
+        insert_into_type_map_ref::<errs::Error>(SchemaTypes::Struct, type_map);
+    }
+}
@@ -1,15 +1,46 @@
 use std::{
+    borrow::Cow,
     collections::{BTreeSet, HashSet},
+    rc::Rc,
     sync::Arc,
 };
 
-use crate::schema::{FieldFormat, FieldType, SchemaField, SchemaFieldRel, TypeMapRef};
+use crate::{
+    schema::{insert_into_type_map_ref, FieldFormat, FieldType, SchemaField, SchemaFieldRel, SchemaTypes, TypeMapRef},
+    Bytes, SchemableType,
+};
 
 pub trait SchemableField {
     fn get_rel_type() -> SchemaFieldRel;
     fn explore_type(_field: &mut SchemaField, _type_map: TypeMapRef);
 }
 
+/// Field of built-in type, without doc comment and serde attributes
+pub(crate) fn plain_field(name: &str, rel: Option<SchemaFieldRel>) -> SchemaField {
+    SchemaField {
+        name: name.to_string(),
+        rel,
+        value: None,
+        description: None,
+        deprecated: false,
+        deprecation_note: None,
+        optional: false,
+        flatten: false,
+        aliases: Vec::new(),
+        example: None,
+        default: None,
+    }
+}
+
+/// Relation of the type, its types are inserted into type map
+pub(crate) fn explore_field_rel<T: SchemableField + ?Sized>(type_map: TypeMapRef) -> Option<SchemaFieldRel> {
+    let mut field = plain_field("", None);
+
+    T::explore_type(&mut field, type_map);
+
+    field.rel
+}
+
 macro_rules! impl_schemable_field {
     ($($t:ty),*) => {
         $(
@@ -52,9 +83,24 @@ impl_schemable_field!(
     time::Date,
     time::OffsetDateTime,
     serde_json::Value,
-    errs::Error
+    Bytes
 );
 
+impl SchemableField for errs::Error {
+    #[inline]
+    fn get_rel_type() -> SchemaFieldRel {
+        SchemaFieldRel::Struct {
+            name: <errs::Error as SchemableType>::type_name(),
+        }
+    }
+
+    fn explore_type(field: &mut SchemaField, type_map: TypeMapRef) {
+        field.rel = Some(<errs::Error as SchemableField>::get_rel_type());
+
+        insert_into_type_map_ref::<errs::Error>(SchemaTypes::Struct, type_map);
+    }
+}
+
 impl<T: SchemableField> SchemableField for Option<T> {
     fn get_rel_type() -> SchemaFieldRel {
         SchemaFieldRel::Nullable {
@@ -121,13 +167,19 @@ impl<T: SchemableField> SchemableField for BTreeSet<T> {
     }
 }
 
-impl<Ok: SchemableField, Err> SchemableField for Result<Ok, Err> {
+/// `Result` in a field is serialized by serde as `{"Ok": value}` or `{"Err": error}`,
+/// so it is described as externally tagged union of both variants
+impl<Ok: SchemableField, Err: SchemableField> SchemableField for Result<Ok, Err> {
     fn get_rel_type() -> SchemaFieldRel {
-        Ok::get_rel_type()
+        SchemaFieldRel::Union {
+            name: Result::<Ok, Err>::type_name(),
+        }
     }
 
     fn explore_type(field: &mut SchemaField, type_map: TypeMapRef) {
-        Ok::explore_type(field, type_map);
+        field.rel = Some(Result::<Ok, Err>::get_rel_type());
+
+        insert_into_type_map_ref::<Result<Ok, Err>>(SchemaTypes::Union, type_map);
     }
 }
 
@@ -170,3 +222,63 @@ where
         field.rel = Some(std::collections::HashMap::<K, V>::get_rel_type());
     }
 }
+
+macro_rules! factory_pointer {
+    ($($t:ident),*) => {
+        $(
+            impl<T: SchemableField> SchemableField for $t<T> {
+                #[inline]
+                fn get_rel_type() -> SchemaFieldRel {
+                    T::get_rel_type()
+                }
+
+                #[inline]
+                fn explore_type(field: &mut SchemaField, type_map: TypeMapRef) {
+                    T::explore_type(field, type_map);
+                }
+            }
+        )*
+    };
+}
+
+factory_pointer!(Box, Arc, Rc);
+
+impl SchemableField for Cow<'_, str> {
+    #[inline]
+    fn get_rel_type() -> SchemaFieldRel {
+        String::get_rel_type()
+    }
+
+    #[inline]
+    fn explore_type(field: &mut SchemaField, type_map: TypeMapRef) {
+        String::explore_type(field, type_map);
+    }
+}
+
+macro_rules! factory_field_tuple ({ $($param:ident)* } => {
+    impl<$($param,)*> SchemableField for ($($param,)*)
+    where
+        $($param: SchemableField,)*
+    {
+        fn get_rel_type() -> SchemaFieldRel {
+            SchemaFieldRel::Tuple {
+                items: vec![$($param::get_rel_type(),)*],
+            }
+        }
+
+        fn explore_type(field: &mut SchemaField, type_map: TypeMapRef) {
+            $($param::explore_type(field, type_map.clone());)*
+
+            field.rel = Some(<($($param,)*) as SchemableField>::get_rel_type());
+        }
+    }
+});
+
+factory_field_tuple! { A }
+factory_field_tuple! { A B }
+factory_field_tuple! { A B C }
+factory_field_tuple! { A B C D }
+factory_field_tuple! { A B C D E }
+factory_field_tuple! { A B C D E F }
+factory_field_tuple! { A B C D E F G }
+factory_field_tuple! { A B C D E F G H }
@@ -1,6 +1,6 @@
 use serde::de::DeserializeOwned;
 
-use crate::{schema::TypeMapRef, SchemaProcedure, SchemableField};
+use crate::{schema::TypeMapRef, Bytes, SchemaProcedure, SchemableField};
 
 pub trait SchemableParams {
     fn apply_schema(proc: &mut SchemaProcedure, type_map: TypeMapRef);
@@ -69,5 +69,6 @@ factory_param!(
     u64,
     f32,
     f64,
-    serde_json::Value
+    serde_json::Value,
+    Bytes
 );
@@ -1,10 +1,13 @@
-use std::sync::Arc;
-
-use crate::{
-    schema::schema_root::{insert_into_type_map_ref, TypeMapRef},
-    SchemaProcedure, SchemableField, SchemableType,
+use std::{
+    borrow::Cow,
+    collections::{BTreeMap, HashMap},
+    sync::Arc,
 };
 
+use crate::{schema::schema_root::TypeMapRef, Bytes, SchemaProcedure, SchemableField};
+
+use super::schemable_field::explore_field_rel;
+
 pub trait SchemableResult {
     fn apply_schema(proc: &mut SchemaProcedure, type_map: TypeMapRef);
 }
@@ -23,12 +26,6 @@ macro_rules! factory_result {
                     proc.result = Some(<$t as SchemableField>::get_rel_type());
                 }
             }
-
-            impl SchemableResult for Vec<$t> {
-                fn apply_schema(proc: &mut SchemaProcedure, _: TypeMapRef) {
-                    proc.result = Some(<Vec<$t> as SchemableField>::get_rel_type());
-                }
-            }
         )*
     };
 }
@@ -53,53 +50,103 @@ factory_result!(
     f32,
     f64,
     serde_json::Value,
-    Option<bool>,
-    Option<String>,
-    Option<&str>,
-    Option<i8>,
-    Option<i16>,
-    Option<i32>,
-    Option<i64>,
-    Option<i128>,
-    Option<isize>,
-    Option<u8>,
-    Option<u16>,
-    Option<u32>,
-    Option<u64>,
-    Option<u128>,
-    Option<usize>,
-    Option<f32>,
-    Option<f64>,
-    Option<serde_json::Value>
+    Bytes
 );
 
-impl<T> SchemableResult for Vec<T>
+/// Items of any schemable type, native types included, eg. `Vec<Uuid>`
+impl<T: SchemableField> SchemableResult for Vec<T> {
+    #[inline]
+    fn apply_schema(proc: &mut SchemaProcedure, type_map: TypeMapRef) {
+        apply_field_schema::<Self>(proc, type_map);
+    }
+}
+
+impl<T: SchemableField> SchemableResult for Option<T> {
+    #[inline]
+    fn apply_schema(proc: &mut SchemaProcedure, type_map: TypeMapRef) {
+        apply_field_schema::<Self>(proc, type_map);
+    }
+}
+
+/// Error of the procedure may be any serializable type, so it is described as opaque JSON value.
+/// Catalogue of typed errors implementing `SchemableError`, eg. enums deriving `RpcError`,
+/// is attached to the procedure by `Procedure::errors`.
+impl<Ok, Err> SchemableResult for Result<Ok, Err>
 where
-    T: SchemableField + SchemableType,
+    Ok: SchemableResult,
 {
     fn apply_schema(proc: &mut SchemaProcedure, type_map: TypeMapRef) {
-        proc.result = Some(<Vec<T> as SchemableField>::get_rel_type());
+        Ok::apply_schema(proc, type_map);
 
-        insert_into_type_map_ref::<T>(T::schema_type(), type_map);
+        proc.error = Some(<serde_json::Value as SchemableField>::get_rel_type());
     }
 }
 
-impl<T> SchemableResult for Option<T>
+/// Set result to the field type and insert its types into type map
+fn apply_field_schema<T: SchemableField>(proc: &mut SchemaProcedure, type_map: TypeMapRef) {
+    proc.result = explore_field_rel::<T>(type_map);
+}
+
+impl<K, V> SchemableResult for HashMap<K, V>
 where
-    T: SchemableField + SchemableType,
+    K: SchemableField,
+    V: SchemableField,
 {
+    #[inline]
     fn apply_schema(proc: &mut SchemaProcedure, type_map: TypeMapRef) {
-        proc.result = Some(<Option<T> as SchemableField>::get_rel_type());
-
-        insert_into_type_map_ref::<T>(T::schema_type(), type_map);
+        apply_field_schema::<Self>(proc, type_map);
     }
 }
 
-impl<Ok, Err> SchemableResult for Result<Ok, Err>
+impl<K, V> SchemableResult for BTreeMap<K, V>
 where
-    Ok: SchemableResult,
+    K: SchemableField,
+    V: SchemableField,
 {
+    #[inline]
     fn apply_schema(proc: &mut SchemaProcedure, type_map: TypeMapRef) {
-        Ok::apply_schema(proc, type_map);
+        apply_field_schema::<Self>(proc, type_map);
+    }
+}
+
+impl SchemableResult for Cow<'_, str> {
+    #[inline]
+    fn apply_schema(proc: &mut SchemaProcedure, type_map: TypeMapRef) {
+        apply_field_schema::<Self>(proc, type_map);
     }
 }
+
+impl<T: SchemableResult> SchemableResult for Box<T> {
+    #[inline]
+    fn apply_schema(proc: &mut SchemaProcedure, type_map: TypeMapRef) {
+        T::apply_schema(proc, type_map);
+    }
+}
+
+impl<T: SchemableResult> SchemableResult for Arc<T> {
+    #[inline]
+    fn apply_schema(proc: &mut SchemaProcedure, type_map: TypeMapRef) {
+        T::apply_schema(proc, type_map);
+    }
+}
+
+macro_rules! factory_result_tuple ({ $($param:ident)* } => {
+    impl<$($param,)*> SchemableResult for ($($param,)*)
+    where
+        $($param: SchemableField,)*
+    {
+        #[inline]
+        fn apply_schema(proc: &mut SchemaProcedure, type_map: TypeMapRef) {
+            apply_field_schema::<Self>(proc, type_map);
+        }
+    }
+});
+
+factory_result_tuple! { A }
+factory_result_tuple! { A B }
+factory_result_tuple! { A B C }
+factory_result_tuple! { A B C D }
+factory_result_tuple! { A B C D E }
+factory_result_tuple! { A B C D E F }
+factory_result_tuple! { A B C D E F G }
+factory_result_tuple! { A B C D E F G H }
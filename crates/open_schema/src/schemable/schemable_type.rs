@@ -1,9 +1,29 @@
-use crate::schema::{schema_type::SchemaTypes, SchemaField, TypeMapRef};
+use crate::{
+    schema::{
+        schema_type::{SchemaTagging, SchemaTypes},
+        SchemaField, SchemaFieldRel, TypeMapRef,
+    },
+    SchemableField,
+};
+
+use super::schemable_field::{explore_field_rel, plain_field};
 
 pub trait SchemableType {
     fn schema_type() -> SchemaTypes;
     fn type_name() -> String;
     fn type_fields(type_map: TypeMapRef) -> Vec<SchemaField>;
+
+    /// Doc comment of the type
+    #[inline]
+    fn type_description() -> Option<String> {
+        None
+    }
+
+    /// Representation of variants, for unions
+    #[inline]
+    fn type_tagging() -> Option<SchemaTagging> {
+        None
+    }
 }
 
 impl<T: SchemableType> SchemableType for Option<T> {
@@ -21,6 +41,16 @@ impl<T: SchemableType> SchemableType for Option<T> {
     fn type_fields(type_map: TypeMapRef) -> Vec<SchemaField> {
         T::type_fields(type_map)
     }
+
+    #[inline]
+    fn type_description() -> Option<String> {
+        T::type_description()
+    }
+
+    #[inline]
+    fn type_tagging() -> Option<SchemaTagging> {
+        T::type_tagging()
+    }
 }
 
 impl<T: SchemableType> SchemableType for Vec<T> {
@@ -38,21 +68,84 @@ impl<T: SchemableType> SchemableType for Vec<T> {
     fn type_fields(type_map: TypeMapRef) -> Vec<SchemaField> {
         T::type_fields(type_map)
     }
+
+    #[inline]
+    fn type_description() -> Option<String> {
+        T::type_description()
+    }
+
+    #[inline]
+    fn type_tagging() -> Option<SchemaTagging> {
+        T::type_tagging()
+    }
 }
 
-impl<Ok: SchemableType, Err> SchemableType for Result<Ok, Err> {
+/// Union of `Ok` and `Err` variants, eg. `Result_User_Error`
+impl<Ok: SchemableField, Err: SchemableField> SchemableType for Result<Ok, Err> {
     #[inline]
     fn schema_type() -> SchemaTypes {
-        Ok::schema_type()
+        SchemaTypes::Union
     }
 
-    #[inline]
     fn type_name() -> String {
-        Ok::type_name()
+        format!(
+            "Result_{}_{}",
+            Ok::get_rel_type().type_name(),
+            Err::get_rel_type().type_name()
+        )
     }
 
-    #[inline]
     fn type_fields(type_map: TypeMapRef) -> Vec<SchemaField> {
-        Ok::type_fields(type_map)
+        let variants = [
+            ("Ok", explore_field_rel::<Ok>(type_map.clone())),
+            ("Err", explore_field_rel::<Err>(type_map)),
+        ];
+
+        variants
+            .into_iter()
+            .map(|(name, rel)| {
+                let mut field = plain_field(name, rel);
+                field.value = Some(name.to_string());
+
+                field
+            })
+            .collect()
+    }
+
+    #[inline]
+    fn type_tagging() -> Option<SchemaTagging> {
+        Some(SchemaTagging::External)
+    }
+}
+
+/// Standard error of procedures, localized `message` is added by the server
+impl SchemableType for errs::Error {
+    #[inline]
+    fn schema_type() -> SchemaTypes {
+        SchemaTypes::Struct
+    }
+
+    #[inline]
+    fn type_name() -> String {
+        String::from("Error")
+    }
+
+    fn type_fields(_: TypeMapRef) -> Vec<SchemaField> {
+        let string = String::get_rel_type();
+        let nullable_string = SchemaFieldRel::Nullable {
+            value: Box::new(string.clone()),
+        };
+
+        let code = plain_field("code", Some(string));
+
+        // Detail is removed by error policy of the server
+        let mut detail = plain_field("detail", Some(nullable_string.clone()));
+        detail.optional = true;
+
+        // Message is present only if the code has translated message
+        let mut message = plain_field("message", Some(nullable_string));
+        message.optional = true;
+
+        vec![code, detail, message]
     }
 }
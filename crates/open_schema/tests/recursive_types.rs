@@ -0,0 +1,143 @@
+use std::rc::Rc;
+
+use rpc_openschema::{
+    schema::{
+        insert_into_type_map_ref, new_type_map_ref, SchemaField, SchemaFieldRel, SchemaType, SchemaTypes, TypeMap,
+        TypeMapRef,
+    },
+    SchemableField, SchemableType,
+};
+
+/// Field of the type, its types are inserted into type map
+fn field<T: SchemableField>(name: &str, type_map: TypeMapRef) -> SchemaField {
+    let mut field = SchemaField {
+        name: name.to_string(),
+        rel: None,
+        value: None,
+        description: None,
+        deprecated: false,
+        deprecation_note: None,
+        optional: false,
+        flatten: false,
+        aliases: Vec::new(),
+        example: None,
+        default: None,
+    };
+
+    T::explore_type(&mut field, type_map);
+
+    field
+}
+
+fn struct_rel(name: &str) -> SchemaFieldRel {
+    SchemaFieldRel::Struct { name: name.to_string() }
+}
+
+/// Build type map of the struct
+fn type_map_of<T: SchemableType>() -> TypeMap {
+    let type_map = new_type_map_ref();
+    insert_into_type_map_ref::<T>(SchemaTypes::Struct, type_map.clone());
+
+    Rc::try_unwrap(type_map).unwrap().into_inner().unwrap()
+}
+
+/// Check that the entry is filled in and it is not the empty placeholder
+fn assert_filled<'a>(type_map: &'a TypeMap, name: &str) -> &'a SchemaType {
+    let ty = type_map.get(name).unwrap();
+
+    assert_eq!(ty.name, name);
+    assert_eq!(ty.ty, SchemaTypes::Struct);
+    assert!(!ty.fields.is_empty(), "type `{name}` has only placeholder entry");
+
+    ty
+}
+
+/// Implement schema of hand-written struct with fields given by `fields` function
+macro_rules! impl_struct {
+    ($t:ident, $fields:expr) => {
+        impl SchemableType for $t {
+            fn schema_type() -> SchemaTypes {
+                SchemaTypes::Struct
+            }
+
+            fn type_name() -> String {
+                String::from(stringify!($t))
+            }
+
+            fn type_fields(type_map: TypeMapRef) -> Vec<SchemaField> {
+                $fields(type_map)
+            }
+        }
+
+        impl SchemableField for $t {
+            fn get_rel_type() -> SchemaFieldRel {
+                struct_rel(stringify!($t))
+            }
+
+            fn explore_type(field: &mut SchemaField, type_map: TypeMapRef) {
+                field.rel = Some(Self::get_rel_type());
+
+                insert_into_type_map_ref::<$t>(SchemaTypes::Struct, type_map);
+            }
+        }
+    };
+}
+
+/// `struct Node { value: i32, children: Vec<Node> }`
+struct Node;
+
+impl_struct!(Node, |type_map: TypeMapRef| vec![
+    field::<i32>("value", type_map.clone()),
+    field::<Vec<Node>>("children", type_map),
+]);
+
+/// `struct A { b: Option<Box<B>> }`
+struct A;
+
+/// `struct B { a: Vec<A> }`
+struct B;
+
+impl_struct!(A, |type_map: TypeMapRef| vec![field::<Option<Box<B>>>("b", type_map)]);
+impl_struct!(B, |type_map: TypeMapRef| vec![field::<Vec<A>>("a", type_map)]);
+
+#[test]
+fn self_recursive_tree_has_one_filled_entry() {
+    let type_map = type_map_of::<Node>();
+
+    assert_eq!(type_map.keys().collect::<Vec<_>>(), ["Node"]);
+
+    let node = assert_filled(&type_map, "Node");
+    let fields = node.fields.iter().map(|field| field.name.as_str()).collect::<Vec<_>>();
+    assert_eq!(fields, ["value", "children"]);
+
+    let children = Some(SchemaFieldRel::Array {
+        value: Box::new(struct_rel("Node")),
+    });
+    assert_eq!(node.fields[1].rel, children);
+}
+
+#[test]
+fn two_type_cycle_has_one_filled_entry_per_type() {
+    // Cycle is entered from both sides
+    for type_map in [type_map_of::<A>(), type_map_of::<B>()] {
+        assert_eq!(type_map.keys().collect::<Vec<_>>(), ["A", "B"]);
+
+        let a = assert_filled(&type_map, "A");
+        assert_eq!(a.fields.len(), 1);
+        assert_eq!(a.fields[0].name, "b");
+
+        let b_rel = Some(SchemaFieldRel::Nullable {
+            value: Box::new(struct_rel("B")),
+        });
+        assert_eq!(a.fields[0].rel, b_rel);
+
+        let b = assert_filled(&type_map, "B");
+        assert_eq!(b.fields.len(), 1);
+        assert_eq!(b.fields[0].name, "a");
+
+        let a_rel = Some(SchemaFieldRel::Array {
+            value: Box::new(struct_rel("A")),
+        });
+        assert_eq!(b.fields[0].rel, a_rel);
+    }
+}
@@ -1,9 +1,25 @@
 #[cfg(any(feature = "openschema", feature = "core", feature = "full"))]
 pub use rpc_macros::Schemable;
 
+#[cfg(any(feature = "openschema", feature = "core", feature = "full"))]
+#[doc(hidden)]
+pub use rpc_openschema::serde;
+
+#[cfg(any(feature = "core", feature = "full"))]
+pub use rpc_macros::RpcError;
+
 #[cfg(any(feature = "core", feature = "full"))]
 pub use rpc_core::app::App;
 
+#[cfg(any(feature = "core", feature = "full"))]
+pub use rpc_core::errors::{self, RpcError};
+
+#[cfg(any(feature = "core", feature = "full"))]
+pub use rpc_core::error_policy::ErrorPolicy;
+
+#[cfg(any(feature = "core", feature = "full"))]
+pub use rpc_core::deprecation::DeprecatedCalls;
+
 #[cfg(any(feature = "core", feature = "full"))]
 pub use rpc_core::call;
 
@@ -13,6 +29,9 @@ pub use rpc_core::extractors;
 #[cfg(any(feature = "core", feature = "full"))]
 pub use rpc_core::json;
 
+#[cfg(any(feature = "core", feature = "full"))]
+pub use rpc_core::messages;
+
 #[cfg(any(feature = "core", feature = "full"))]
 pub use rpc_core::procedure;
 
@@ -22,6 +41,15 @@ pub use rpc_core::responder::*;
 #[cfg(any(feature = "core", feature = "full"))]
 pub use rpc_core::router;
 
+#[cfg(any(feature = "core", feature = "full"))]
+pub use rpc_core::runtime;
+
+#[cfg(any(feature = "core", feature = "full"))]
+pub use rpc_core::state;
+
+#[cfg(any(feature = "core", feature = "full"))]
+pub use rpc_core::transport;
+
 #[cfg(feature = "full")]
 pub mod server {
     pub use rpc_server::*;